@@ -34,7 +34,7 @@ fn plot_path() {
     let duration = second(2.5);
     let res = path.follow(velocity, duration);
 
-    let traj_res = traj.segment().follow(velocity, duration);
+    let traj_res = traj.transition().follow(velocity, duration);
     println!(
         "{} {:?}",
         traj_res.pose,
@@ -42,41 +42,14 @@ fn plot_path() {
     );
 
     {
-        use plotters::prelude::*;
-        let root = BitMapBackend::new("geometrie.png", (1000, 1000)).into_drawing_area();
-        root.fill(&WHITE).unwrap();
-
-        let x_min = -1.0;
-        let x_max = 5.0;
-        let y_min = -1.0;
-        let y_max = 5.0;
-
-        let mut chart: ChartContext<
-            '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        > = ChartBuilder::on(&root)
-            // .caption("y=x^2", ("sans-serif", 50).into_font())
-            .margin(5)
-            .x_label_area_size(50)
-            .y_label_area_size(50)
-            .build_cartesian_2d(x_min..x_max, y_min..y_max)
+        use plotters::prelude::{BLACK, BLUE, RED};
+        Scene::new()
+            .add_labelled(&p, BLACK, "field")
+            .add_labelled(&start, RED, "start")
+            .add_labelled(&path, BLUE, "path")
+            .add(&res.pose, Style::new(BLUE, 1, 5))
+            .save("geometrie.png")
             .unwrap();
-        chart.configure_mesh().draw().unwrap();
-        //
-        p.draw(&mut chart, BLACK);
-        start.draw(&mut chart, RED, 5, RED.stroke_width(2), 1.0);
-        // for seg in segments.iter() {
-        //     seg.draw(&mut chart, RGBAColor(50, 50, 50, 0.5).stroke_width(10));
-        // }
-        path.draw(&mut chart, BLUE.stroke_width(2));
-        // point.draw(&mut chart, BLACK, 5);
-        res.pose.draw(&mut chart, BLUE, 5, BLUE, 1.0);
-        //
-        root.present().unwrap();
     }
 }
 
@@ -93,40 +66,18 @@ pub fn plot_trajectory() {
     let trajectory = Trajectory::create(&start, &finish, radius).unwrap();
 
     {
-        use plotters::prelude::*;
-        let root = BitMapBackend::new("geometrie.png", (1000, 1000)).into_drawing_area();
-        root.fill(&WHITE).unwrap();
-
-        let x_min = 0.0;
-        let x_max = 10.0;
-        let y_min = 0.0;
-        let y_max = 10.0;
-
-        let mut chart: ChartContext<
-            // '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        > = ChartBuilder::on(&root)
-            // .caption("y=x^2", ("sans-serif", 50).into_font())
-            .margin(5)
-            .x_label_area_size(50)
-            .y_label_area_size(50)
-            .build_cartesian_2d(x_min..x_max, y_min..y_max)
-            .unwrap();
-        chart.configure_mesh().draw().unwrap();
-        //
-        start.draw(&mut chart, GREEN, 5, GREEN.stroke_width(2), 1.0);
-        finish.draw(&mut chart, RED, 5, RED.stroke_width(2), 1.0);
-
+        use plotters::prelude::{RGBColor, BLUE, GREEN, RED};
+        let mut scene = Scene::new();
+        scene
+            .add_labelled(&start, GREEN, "start")
+            .add_labelled(&finish, RED, "finish");
         for traj in list.iter() {
-            traj.draw(&mut chart, RGBAColor(200, 200, 200, 1.0).stroke_width(2));
+            scene.add(traj, RGBColor(200, 200, 200));
         }
-        trajectory.draw(&mut chart, BLUE.stroke_width(2));
-        //
-        root.present().unwrap();
+        scene
+            .add_labelled(&trajectory, BLUE, "shortest")
+            .save("geometrie.png")
+            .unwrap();
     }
 }
 
@@ -151,40 +102,17 @@ pub fn plot_test() {
     let pose = Pose::new(p, v);
 
     {
-        use plotters::prelude::*;
-        let root = BitMapBackend::new("geometrie.png", (1000, 1000)).into_drawing_area();
-        root.fill(&WHITE).unwrap();
-
-        let x_min = -1.0;
-        let x_max = 5.0;
-        let y_min = -1.0;
-        let y_max = 5.0;
-
-        let mut chart: ChartContext<
-            '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        > = ChartBuilder::on(&root)
-            // .caption("y=x^2", ("sans-serif", 50).into_font())
-            .margin(5)
-            .x_label_area_size(50)
-            .y_label_area_size(50)
-            .build_cartesian_2d(x_min..x_max, y_min..y_max)
+        use plotters::prelude::{BLACK, BLUE, GREEN, RED};
+        Scene::new()
+            .add(d1.circle(), Style::new(BLACK, 1, 5))
+            .add(&c2, Style::new(BLACK, 1, 5))
+            .add_labelled(&seg, BLUE, "tangent")
+            .add_labelled(&arc, RED, "arc")
+            .add(&arc.start_point(), Style::new(BLACK, 1, 5))
+            .add(&arc.finish_point(), Style::new(GREEN, 1, 5))
+            .add_labelled(&pose, GREEN, "pose")
+            .save("geometrie.png")
             .unwrap();
-        chart.configure_mesh().draw().unwrap();
-        //
-        d1.circle().draw(&mut chart, BLACK);
-        c2.draw(&mut chart, BLACK);
-        seg.draw(&mut chart, BLUE.stroke_width(2));
-        arc.draw(&mut chart, RED.stroke_width(2));
-        arc.start_point().draw(&mut chart, BLACK, 5);
-        arc.finish_point().draw(&mut chart, GREEN, 5);
-        pose.draw(&mut chart, GREEN, 5, GREEN.stroke_width(2), 5.0);
-        //
-        root.present().unwrap();
     }
 }
 
@@ -202,108 +130,57 @@ pub fn plot_poly() {
     let segments = p.mapping(&direction, metre(0.25));
 
     {
-        use plotters::prelude::*;
-        let root = BitMapBackend::new("geometrie.png", (1000, 1000)).into_drawing_area();
-        root.fill(&WHITE).unwrap();
-
-        let x_min = -1.0;
-        let x_max = 5.0;
-        let y_min = -1.0;
-        let y_max = 5.0;
-
-        let mut chart: ChartContext<
-            '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        > = ChartBuilder::on(&root)
-            // .caption("y=x^2", ("sans-serif", 50).into_font())
-            .margin(5)
-            .x_label_area_size(50)
-            .y_label_area_size(50)
-            .build_cartesian_2d(x_min..x_max, y_min..y_max)
-            .unwrap();
-        chart.configure_mesh().draw().unwrap();
-        //
-        p.draw(&mut chart, BLACK);
-        center.draw(&mut chart, RED, 5);
+        use plotters::prelude::{BLACK, BLUE, RED};
+        let mut scene = Scene::new();
+        scene
+            .add_labelled(&p, Style::new(BLACK, 1, 5), "field")
+            .add_labelled(&center, Style::new(RED, 1, 5), "center");
         for seg in segments.iter() {
-            seg.draw(&mut chart, BLUE);
+            scene.add(seg, Style::new(BLUE, 1, 5));
         }
-        //
-        root.present().unwrap();
+        scene.save("geometrie.png").unwrap();
     }
 }
 
 pub fn plot_circle_tangents() -> Result<(), Box<dyn std::error::Error>> {
-    use plotters::prelude::*;
-
-    // Création d'une zone de dessin
-    let root = BitMapBackend::new("geometrie.png", (1000, 1000)).into_drawing_area();
-    root.fill(&WHITE)?;
-
-    let x_min = -10.0;
-    let x_max = 20.0;
-    let y_min = -10.0;
-    let y_max = 20.0;
-
-    let mut chart: ChartContext<
-        '_,
-        BitMapBackend<'_>,
-        Cartesian2d<plotters::coord::types::RangedCoordf64, plotters::coord::types::RangedCoordf64>,
-    > = ChartBuilder::on(&root)
-        // .caption("y=x^2", ("sans-serif", 50).into_font())
-        .margin(5)
-        .x_label_area_size(50)
-        .y_label_area_size(50)
-        .build_cartesian_2d(x_min..x_max, y_min..y_max)?;
-    chart.configure_mesh().draw()?;
-
-    {
-        use geometry2d::*;
-        use geomety::*;
-
-        let p1 = Point::new(0.0, 0.0);
-        let r1 = 2.0;
-        let c1 = Circle::new(p1, r1);
-        let p2 = Point::new(10.0, 2.0);
-        let c2 = Circle::new(p2.clone(), r1);
-
-        c1.draw(&mut chart, BLACK);
-        c2.draw(&mut chart, BLACK);
-
-        let (s1, s2) = c1.external_tangents_circle(&p2);
-        println!("X1 = ({}, {})", s1.first().x, s1.first().y);
-        println!("Y1 = ({}, {})", s1.second().x, s1.second().y);
-        println!("X2 = ({}, {})", s2.first().x, s2.first().y);
-        println!("Y2 = ({}, {})", s2.second().x, s2.second().y);
-        s1.draw(&mut chart, BLUE);
-        s2.draw(&mut chart, BLUE);
-        println!("----------");
-        let (s1, s2) = c1.internal_tangents_circle(&p2).unwrap();
-        println!("A1 = ({}, {})", s1.first().x, s1.first().y);
-        println!("B1 = ({}, {})", s1.second().x, s1.second().y);
-        println!("A2 = ({}, {})", s2.first().x, s2.first().y);
-        println!("B2 = ({}, {})", s2.second().x, s2.second().y);
-        s1.draw(&mut chart, RED);
-        s2.draw(&mut chart, RED);
-
-        let points = vec![
-            Point::new(0.0, 3.0),
-            Point::new(2.0, 2.0),
-            Point::new(1.0, 1.0),
-            Point::new(2.0, 1.0),
-            Point::new(3.0, 0.0),
-            Point::new(0.0, 0.0),
-            Point::new(3.0, 3.0),
-        ];
-
-        let polygon = Polygon::convex_hull(points).unwrap();
-        polygon.draw(&mut chart, GREEN);
-    }
-
-    root.present()?;
+    use plotters::prelude::{BLACK, BLUE, GREEN, RED};
+
+    let p1 = Point::new(0.0, 0.0);
+    let r1 = 2.0;
+    let c1 = Circle::new(p1, r1);
+    let p2 = Point::new(10.0, 2.0);
+    let c2 = Circle::new(p2.clone(), r1);
+
+    let mut scene = Scene::new();
+    scene.add(&c1, BLACK).add(&c2, BLACK);
+
+    let (s1, s2) = c1.external_tangents_circle(&p2);
+    println!("X1 = ({}, {})", s1.first().x, s1.first().y);
+    println!("Y1 = ({}, {})", s1.second().x, s1.second().y);
+    println!("X2 = ({}, {})", s2.first().x, s2.first().y);
+    println!("Y2 = ({}, {})", s2.second().x, s2.second().y);
+    scene.add_labelled(&s1, BLUE, "external").add(&s2, BLUE);
+    println!("----------");
+    let (s1, s2) = c1.internal_tangents_circle(&p2).unwrap();
+    println!("A1 = ({}, {})", s1.first().x, s1.first().y);
+    println!("B1 = ({}, {})", s1.second().x, s1.second().y);
+    println!("A2 = ({}, {})", s2.first().x, s2.first().y);
+    println!("B2 = ({}, {})", s2.second().x, s2.second().y);
+    scene.add_labelled(&s1, RED, "internal").add(&s2, RED);
+
+    let points = vec![
+        Point::new(0.0, 3.0),
+        Point::new(2.0, 2.0),
+        Point::new(1.0, 1.0),
+        Point::new(2.0, 1.0),
+        Point::new(3.0, 0.0),
+        Point::new(0.0, 0.0),
+        Point::new(3.0, 3.0),
+    ];
+
+    let polygon = Polygon::convex_hull(points).unwrap();
+    scene.add_labelled(&polygon, GREEN, "convex hull");
+
+    scene.save("geometrie.png")?;
     Ok(())
 }
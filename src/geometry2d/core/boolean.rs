@@ -0,0 +1,423 @@
+use super::*;
+use std::ops::Mul;
+
+use sity::*;
+
+/// A boolean set operation between two surfaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOperation {
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+//-------------------------------------------------- Operations --------------------------------------------------
+
+impl<T> MultiPolygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Applies `operation` between `self` and `other`. Inputs may be concave and have holes;
+    /// touching boundaries are merged or removed.
+    pub fn boolean(&self, other: &Self, operation: BooleanOperation) -> Self {
+        let a = rings_of(self);
+        let b = rings_of(other);
+        let eps = tolerance(&a, &b);
+        let rings = link(&select(&a, &b, operation, eps), eps);
+        assemble(rings, eps)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.boolean(other, BooleanOperation::Union)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.boolean(other, BooleanOperation::Intersection)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.boolean(other, BooleanOperation::Difference)
+    }
+
+    pub fn xor(&self, other: &Self) -> Self {
+        self.boolean(other, BooleanOperation::Xor)
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    pub fn boolean(&self, other: &Self, operation: BooleanOperation) -> MultiPolygon<T> {
+        MultiPolygon::from(self.clone()).boolean(&other.clone().into(), operation)
+    }
+
+    pub fn union(&self, other: &Self) -> MultiPolygon<T> {
+        self.boolean(other, BooleanOperation::Union)
+    }
+
+    pub fn intersection(&self, other: &Self) -> MultiPolygon<T> {
+        self.boolean(other, BooleanOperation::Intersection)
+    }
+
+    pub fn difference(&self, other: &Self) -> MultiPolygon<T> {
+        self.boolean(other, BooleanOperation::Difference)
+    }
+
+    pub fn xor(&self, other: &Self) -> MultiPolygon<T> {
+        self.boolean(other, BooleanOperation::Xor)
+    }
+}
+
+//-------------------------------------------------- Internals --------------------------------------------------
+
+/// An oriented edge of a ring, in values.
+pub(super) type Edge = (Point<f64>, Point<f64>);
+
+fn same(a: &Point<f64>, b: &Point<f64>, eps: f64) -> bool {
+    (a.x - b.x).abs() <= eps && (a.y - b.y).abs() <= eps
+}
+
+fn middle(a: &Point<f64>, b: &Point<f64>) -> Point<f64> {
+    Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
+
+/// Returns the rings of every polygon in values: outer rings counter-clockwise, holes clockwise.
+pub(super) fn rings_of<T>(polygons: &MultiPolygon<T>) -> Vec<Polygon<f64>>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    let mut rings = vec![];
+    for polygon in polygons.polygons() {
+        for (i, ring) in polygon.rings().enumerate() {
+            if ring.points().len() < 3 {
+                continue;
+            }
+            let winding = if i == 0 {
+                Orientation::CounterClockWise
+            } else {
+                Orientation::ClockWise
+            };
+            rings.push(ring.to_value().with_winding(winding));
+        }
+    }
+    rings
+}
+
+pub(super) fn tolerance(a: &[Polygon<f64>], b: &[Polygon<f64>]) -> f64 {
+    let extent = a
+        .iter()
+        .chain(b.iter())
+        .flat_map(|ring| ring.points())
+        .fold(1.0_f64, |m, p| m.max(p.x.abs()).max(p.y.abs()));
+    1e-9 * extent
+}
+
+/// Sum of the winding numbers of the oriented rings around `p`: positive inside.
+pub(super) fn winding(rings: &[Polygon<f64>], p: &Point<f64>) -> i32 {
+    rings.iter().map(|ring| ring.winding_number(p)).sum()
+}
+
+/// Returns the edges of the ring, each vertex to the next one.
+fn edges(ring: &Polygon<f64>) -> Vec<Edge> {
+    ring.segments()
+        .into_iter()
+        .map(|seg| (seg.first().clone(), seg.second().clone()))
+        .collect()
+}
+
+/// Returns the points where segment `[a, b]` touches segment `[c, d]`.
+fn contacts(
+    a: &Point<f64>,
+    b: &Point<f64>,
+    c: &Point<f64>,
+    d: &Point<f64>,
+    eps: f64,
+) -> Vec<Point<f64>> {
+    let r = Vector::from((a, b));
+    let s = Vector::from((c, d));
+    let (lr, ls) = (r.norm(), s.norm());
+    let den = r.cross_product(&s);
+    let ac = Vector::from((a, c));
+    if den.abs() > eps * (lr + ls) {
+        let t = ac.cross_product(&s) / den;
+        let u = ac.cross_product(&r) / den;
+        if t * lr >= -eps && t * lr <= lr + eps && u * ls >= -eps && u * ls <= ls + eps {
+            return vec![a + r * t];
+        }
+        return vec![];
+    }
+    // Parallel: only collinear overlaps matter
+    if (r.cross_product(&ac) / lr).abs() > eps {
+        return vec![];
+    }
+    let mut points = vec![];
+    for p in [a, b] {
+        let t = Vector::from((c, p)).dot_product(&s) / (ls * ls);
+        if t * ls >= -eps && t * ls <= ls + eps {
+            points.push(p.clone());
+        }
+    }
+    for p in [c, d] {
+        let t = Vector::from((a, p)).dot_product(&r) / (lr * lr);
+        if t * lr >= -eps && t * lr <= lr + eps {
+            points.push(p.clone());
+        }
+    }
+    points
+}
+
+/// Splits every edge of `rings` at its contacts with the edges of `others`.
+fn split(rings: &[Polygon<f64>], others: &[Polygon<f64>], eps: f64) -> Vec<Edge> {
+    let mut pieces = vec![];
+    for (a, b) in rings.iter().flat_map(edges) {
+        let r = Vector::from((&a, &b));
+        let l = r.norm();
+        let mut cuts = vec![];
+        for (c, d) in others.iter().flat_map(edges) {
+            for p in contacts(&a, &b, &c, &d, eps) {
+                let t = Vector::from((&a, &p)).dot_product(&r) / l;
+                if t > eps && t < l - eps {
+                    cuts.push((t, p));
+                }
+            }
+        }
+        cuts.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(std::cmp::Ordering::Equal));
+        let mut from = a;
+        for (_, p) in cuts {
+            if !same(&from, &p, eps) {
+                pieces.push((from, p.clone()));
+                from = p;
+            }
+        }
+        if !same(&from, &b, eps) {
+            pieces.push((from, b));
+        }
+    }
+    pieces
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Side {
+    Inside,
+    Outside,
+    SharedSame,
+    SharedOpposite,
+}
+
+fn classify(piece: &Edge, other_pieces: &[Edge], other_rings: &[Polygon<f64>], eps: f64) -> Side {
+    for (p, q) in other_pieces {
+        if same(&piece.0, p, eps) && same(&piece.1, q, eps) {
+            return Side::SharedSame;
+        }
+        if same(&piece.0, q, eps) && same(&piece.1, p, eps) {
+            return Side::SharedOpposite;
+        }
+    }
+    if winding(other_rings, &middle(&piece.0, &piece.1)) > 0 {
+        Side::Inside
+    } else {
+        Side::Outside
+    }
+}
+
+/// Keeps the oriented edges bounding the result, with the result on their left.
+pub(super) fn select(
+    a: &[Polygon<f64>],
+    b: &[Polygon<f64>],
+    operation: BooleanOperation,
+    eps: f64,
+) -> Vec<Edge> {
+    use BooleanOperation::*;
+    let pieces_a = split(a, b, eps);
+    let pieces_b = split(b, a, eps);
+    let reversed = |(p, q): &Edge| (q.clone(), p.clone());
+    let mut edges = vec![];
+    for piece in pieces_a.iter() {
+        let keep = match (classify(piece, &pieces_b, b, eps), operation) {
+            (Side::Outside, Union | Difference | Xor) => Some(piece.clone()),
+            (Side::Inside, Intersection) => Some(piece.clone()),
+            (Side::Inside, Xor) => Some(reversed(piece)),
+            (Side::SharedSame, Union | Intersection) => Some(piece.clone()),
+            (Side::SharedOpposite, Difference) => Some(piece.clone()),
+            _ => None,
+        };
+        edges.extend(keep);
+    }
+    for piece in pieces_b.iter() {
+        let keep = match (classify(piece, &pieces_a, a, eps), operation) {
+            (Side::Outside, Union | Xor) => Some(piece.clone()),
+            (Side::Inside, Intersection) => Some(piece.clone()),
+            (Side::Inside, Difference | Xor) => Some(reversed(piece)),
+            _ => None,
+        };
+        edges.extend(keep);
+    }
+    edges
+}
+
+/// Links the edges into closed rings, taking the sharpest left turn where several edges meet.
+pub(super) fn link(edges: &[Edge], eps: f64) -> Vec<Polygon<f64>> {
+    let mut vertices: Vec<Point<f64>> = vec![];
+    let mut id = |p: &Point<f64>| match vertices.iter().position(|v| same(v, p, eps)) {
+        Some(i) => i,
+        None => {
+            vertices.push(p.clone());
+            vertices.len() - 1
+        }
+    };
+    let ids = edges
+        .iter()
+        .map(|(p, q)| (id(p), id(q)))
+        .filter(|(i, j)| i != j)
+        .collect::<Vec<_>>();
+
+    let mut used = vec![false; ids.len()];
+    let mut rings = vec![];
+    for start in 0..ids.len() {
+        if used[start] {
+            continue;
+        }
+        let mut ring = vec![];
+        let mut current = start;
+        let closed = loop {
+            used[current] = true;
+            let (from, to) = ids[current];
+            ring.push(vertices[from].clone());
+            if to == ids[start].0 {
+                break true;
+            }
+            let incoming = Vector::from((&vertices[from], &vertices[to]));
+            let next = (0..ids.len())
+                .filter(|e| !used[*e] && ids[*e].0 == to)
+                .max_by(|x, y| {
+                    let turn = |e: usize| {
+                        let outgoing = Vector::from((&vertices[to], &vertices[ids[e].1]));
+                        incoming
+                            .cross_product(&outgoing)
+                            .atan2(incoming.dot_product(&outgoing))
+                    };
+                    turn(*x)
+                        .partial_cmp(&turn(*y))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+            match next {
+                Some(e) => current = e,
+                None => break false,
+            }
+        };
+        if closed {
+            rings.push(Polygon::new_unchecked(ring));
+        }
+    }
+    rings
+}
+
+/// Returns the rings bounding the union of all `pieces`, each piece being a counter-clockwise ring
+/// or a set of rings oriented as in a polygon with holes.
+pub(super) fn union_rings(pieces: &[Polygon<f64>], eps: f64) -> Vec<Polygon<f64>> {
+    let mut edges: Vec<Edge> = vec![];
+    for piece in pieces {
+        for (p, q) in split(std::slice::from_ref(piece), pieces, eps) {
+            // Keep the edge if the union is on its left only
+            let v = Vector::from((&p, &q));
+            let delta = (100.0 * eps).min(v.norm() / 4.0);
+            let normal = v.perpendicular_counterclockwise().scale(delta);
+            let middle = middle(&p, &q);
+            if winding(pieces, &(&middle + &normal)) > 0
+                && winding(pieces, &(&middle - &normal)) <= 0
+                && !edges
+                    .iter()
+                    .any(|(a, b)| same(a, &p, eps) && same(b, &q, eps))
+            {
+                edges.push((p, q));
+            }
+        }
+    }
+    link(&edges, eps)
+}
+
+/// Removes the vertices lying on the line joining their neighbours.
+pub(super) fn simplify(ring: Polygon<f64>, eps: f64) -> Polygon<f64> {
+    let mut ring = ring.points().clone();
+    let mut i = 0;
+    while ring.len() >= 3 && i < ring.len() {
+        let n = ring.len();
+        let previous = &ring[(i + n - 1) % n];
+        let v = Vector::from((previous, &ring[(i + 1) % n]));
+        let l = v.norm();
+        if l <= eps || (v.cross_product(&Vector::from((previous, &ring[i]))) / l).abs() <= eps {
+            ring.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+    Polygon::new_unchecked(ring)
+}
+
+/// Groups the rings into polygons: each clockwise ring is a hole of the smallest
+/// counter-clockwise ring around it.
+pub(super) fn assemble<T>(rings: Vec<Polygon<f64>>, eps: f64) -> MultiPolygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    let mut outers = vec![];
+    let mut holes = vec![];
+    for ring in rings {
+        let ring = simplify(ring, eps);
+        if ring.points().len() < 3 || ring.area() <= eps / 2.0 {
+            continue;
+        }
+        match ring.winding() {
+            Orientation::CounterClockWise => outers.push((ring.area(), ring, vec![])),
+            Orientation::ClockWise => holes.push(ring),
+            Orientation::Collinear => {}
+        }
+    }
+    for hole in holes {
+        // A point just inside the hole, on the right of its first edge
+        let (a, b) = (&hole.points()[0], &hole.points()[1]);
+        let inward = Vector::from((a, b))
+            .perpendicular_clockwise()
+            .scale(1e3 * eps);
+        let probe = &middle(a, b) + &inward;
+        let owner = outers
+            .iter_mut()
+            .filter(|(_, outer, _)| outer.winding_number(&probe) != 0)
+            .min_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(std::cmp::Ordering::Equal));
+        if let Some((_, _, owned)) = owner {
+            owned.push(hole);
+        }
+    }
+
+    let to_polygon = |ring: Polygon<f64>| {
+        Polygon::new_unchecked(
+            ring.points()
+                .iter()
+                .map(|p| Point::new(T::ONE * p.x, T::ONE * p.y))
+                .collect(),
+        )
+    };
+    MultiPolygon::new(
+        outers
+            .into_iter()
+            .map(|(_, outer, holes)| {
+                PolygonWithHoles::new_unchecked(
+                    to_polygon(outer),
+                    holes.into_iter().map(to_polygon).collect(),
+                )
+            })
+            .collect(),
+    )
+}
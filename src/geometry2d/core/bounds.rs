@@ -0,0 +1,388 @@
+use super::*;
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::fmt::Display;
+use std::ops::{Div, Mul};
+
+use sity::*;
+
+//-------------------------------------------------- Aabb --------------------------------------------------
+
+/// An axis-aligned bounding box, from its lower left corner `min` to its upper right corner `max`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Aabb<T: Number> {
+    min: Point<T>,
+    max: Point<T>,
+}
+
+impl<T: Number> Aabb<T> {
+    /// Creates the box having `p1` and `p2` as opposite corners.
+    pub fn new(p1: Point<T>, p2: Point<T>) -> Self {
+        Self {
+            min: Point::new(p1.x.min(p2.x), p1.y.min(p2.y)),
+            max: Point::new(p1.x.max(p2.x), p1.y.max(p2.y)),
+        }
+    }
+
+    /// Returns the smallest box containing all the points, `None` if there are none.
+    pub fn from_points<'a, I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Point<T>>,
+        T: 'a,
+    {
+        points
+            .into_iter()
+            .map(|p| Self::new(p.clone(), p.clone()))
+            .reduce(|a, b| a.union(&b))
+    }
+
+    pub fn min(&self) -> &Point<T> {
+        &self.min
+    }
+
+    pub fn max(&self) -> &Point<T> {
+        &self.max
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y
+    }
+
+    /// Returns the smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    /// Returns the common part of both boxes, `None` if they are disjoint.
+    /// Boxes touching along an edge or at a corner give a flat box.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(Self { min, max })
+    }
+
+    /// Whether both boxes share at least a point.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the box grown by `margin` on every side.
+    pub fn expanded(&self, margin: T) -> Self {
+        Self::new(
+            Point::new(self.min.x - margin, self.min.y - margin),
+            Point::new(self.max.x + margin, self.max.y + margin),
+        )
+    }
+}
+
+impl<T> Aabb<T>
+where
+    T: Number,
+{
+    pub fn to_value(&self) -> Aabb<<T as HasValue>::Output> {
+        Aabb {
+            min: self.min.to_value(),
+            max: self.max.to_value(),
+        }
+    }
+}
+
+impl<T: Number> Contains<Point<T>> for Aabb<T> {
+    /// Points on the boundary are contained.
+    fn contains(&self, point: &Point<T>) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+    }
+}
+
+impl<T: Number> Contains<Aabb<T>> for Aabb<T> {
+    fn contains(&self, other: &Aabb<T>) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+}
+
+impl<T: Number + Display> Display for Aabb<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{} .. {}]", self.min, self.max)
+    }
+}
+
+//-------------------------------------------------- Bounded --------------------------------------------------
+
+/// A shape with a finite extent.
+pub trait Bounded<T>
+where
+    T: Number,
+{
+    /// Returns the smallest axis-aligned box containing the shape.
+    fn aabb(&self) -> Aabb<T>;
+
+    /// Whether the shape may share a point with `area`. Always true when they do; by default,
+    /// also when only the box of the shape meets `area`.
+    fn overlaps(&self, area: &Aabb<T>) -> bool {
+        self.aabb().intersects(area)
+    }
+}
+
+impl<T: Number> Bounded<T> for Point<T> {
+    fn aabb(&self) -> Aabb<T> {
+        Aabb::new(self.clone(), self.clone())
+    }
+}
+
+impl<T> Bounded<T> for Segment<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    fn aabb(&self) -> Aabb<T> {
+        Aabb::new(self.first().clone(), self.second().clone())
+    }
+
+    /// Exact: the boxes meet and the corners of `area` are not all strictly on one side of the
+    /// segment.
+    fn overlaps(&self, area: &Aabb<T>) -> bool {
+        if !self.aabb().intersects(area) {
+            return false;
+        }
+        let (a, b) = (self.first(), self.second());
+        let (min, max) = (area.min(), area.max());
+        let sides = [
+            (min.x, min.y),
+            (max.x, min.y),
+            (max.x, max.y),
+            (min.x, max.y),
+        ]
+        .map(|(x, y)| (b.x - a.x) * (y - a.y) - (b.y - a.y) * (x - a.x));
+        let zero = <T as Mul>::Output::ZERO;
+        !(sides.iter().all(|s| *s > zero) || sides.iter().all(|s| *s < zero))
+    }
+}
+
+impl<T: Number> Bounded<T> for Polygon<T> {
+    fn aabb(&self) -> Aabb<T> {
+        Aabb::from_points(self.points()).unwrap_or_default()
+    }
+}
+
+impl<T: Number> Bounded<T> for Circle<T> {
+    fn aabb(&self) -> Aabb<T> {
+        let (c, r) = (self.center(), self.radius());
+        Aabb::new(Point::new(c.x - r, c.y - r), Point::new(c.x + r, c.y + r))
+    }
+}
+
+impl<T> Bounded<T> for DirectedArc<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// The box of the end points and of the axis extremes the arc goes through.
+    fn aabb(&self) -> Aabb<T> {
+        let (c, r) = (self.center(), self.radius().value());
+        let (from, delta) = self.range();
+        let point = |angle: f64| {
+            Point::new(
+                c.x + T::ONE * (r * angle.cos()),
+                c.y + T::ONE * (r * angle.sin()),
+            )
+        };
+        let extremes = (0..4)
+            .map(|k| k as f64 * FRAC_PI_2)
+            .filter(|a| (a - from).rem_euclid(2.0 * PI) <= delta)
+            .map(point);
+        let points = [point(from), point(from + delta)]
+            .into_iter()
+            .chain(extremes)
+            .collect::<Vec<_>>();
+        Aabb::from_points(&points).unwrap_or_default()
+    }
+}
+
+impl<T> Bounded<T> for Trajectory<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    fn aabb(&self) -> Aabb<T> {
+        let transition = match self.transition() {
+            Transition::Segment(seg) => Aabb::new(seg.first().clone(), seg.second().clone()),
+            Transition::Arc(arc) => arc.aabb(),
+        };
+        self.start_rotation()
+            .aabb()
+            .union(&transition)
+            .union(&self.finish_rotation().aabb())
+    }
+}
+
+impl<T> Bounded<T> for Path<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// An empty path has the default (degenerate) box.
+    fn aabb(&self) -> Aabb<T> {
+        self.trajectories()
+            .iter()
+            .map(|traj| traj.aabb())
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or_default()
+    }
+}
+
+//-------------------------------------------------- Obb --------------------------------------------------
+
+/// An oriented bounding box: a rectangle of `length` along `angle` and `width` across it,
+/// centered on `center`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Obb<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    center: Point<T>,
+    angle: Radian<<T as HasValue>::Output>,
+    length: T,
+    width: T,
+}
+
+impl<T> Obb<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    pub fn new(
+        center: Point<T>,
+        angle: Radian<<T as HasValue>::Output>,
+        length: T,
+        width: T,
+    ) -> Self {
+        Self {
+            center,
+            angle,
+            length,
+            width,
+        }
+    }
+
+    pub fn center(&self) -> &Point<T> {
+        &self.center
+    }
+
+    /// The direction of the length of the box.
+    pub fn angle(&self) -> Radian<<T as HasValue>::Output> {
+        self.angle
+    }
+
+    pub fn length(&self) -> T {
+        self.length
+    }
+
+    pub fn width(&self) -> T {
+        self.width
+    }
+}
+
+impl<T> Obb<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Returns the corners, counter-clockwise.
+    pub fn corners(&self) -> [Point<T>; 4] {
+        let (cos, sin) = (self.angle.value().cos(), self.angle.value().sin());
+        let (l, w) = (self.length.value() / 2.0, self.width.value() / 2.0);
+        [(-l, -w), (l, -w), (l, w), (-l, w)].map(|(a, b)| {
+            Point::new(
+                self.center.x + T::ONE * (a * cos - b * sin),
+                self.center.y + T::ONE * (a * sin + b * cos),
+            )
+        })
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Number + AngleFactory,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+    T: Pow2<Output = <T as Mul>::Output>,
+    <T as Pow2>::Output: Root2<Output = T>,
+    <T as Mul>::Output: Div,
+    <<T as Mul>::Output as Div>::Output: Number,
+    T: Mul<<<T as Mul>::Output as Div>::Output, Output = T>,
+{
+    /// Returns the oriented box of minimum area containing the polygon, found by rotating
+    /// calipers on its convex hull. One side of this box is along a hull edge.
+    pub fn min_area_box(&self) -> Option<Obb<T>> {
+        // Counter-clockwise, so that the normal of an edge points inside
+        let hull = Self::convex_hull(self.points().clone())?.to_value();
+        let points = hull.points().iter().map(|p| [p.x, p.y]).collect::<Vec<_>>();
+        let n = points.len();
+        let dot = |p: usize, u: [f64; 2]| points[p % n][0] * u[0] + points[p % n][1] * u[1];
+
+        // (area, angle, center, length, width)
+        let mut best: Option<(f64, f64, [f64; 2], f64, f64)> = None;
+        let (mut right, mut top, mut left) = (0, 0, 0);
+        for i in 0..n {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            let norm = (b[0] - a[0]).hypot(b[1] - a[1]);
+            if norm <= 0.0 {
+                continue;
+            }
+            // `v` points inside
+            let u = [(b[0] - a[0]) / norm, (b[1] - a[1]) / norm];
+            let v = [-u[1], u[0]];
+            if best.is_none() {
+                (right, top, left) = (i, i, i);
+            }
+            // The calipers only move forward
+            right = right.max(i);
+            while dot(right + 1, u) > dot(right, u) {
+                right += 1;
+            }
+            top = top.max(right);
+            while dot(top + 1, v) > dot(top, v) {
+                top += 1;
+            }
+            left = left.max(top);
+            while dot(left + 1, u) < dot(left, u) {
+                left += 1;
+            }
+
+            let (min, max) = (dot(left, u), dot(right, u));
+            let (bottom, height) = (dot(i, v), dot(top, v) - dot(i, v));
+            let area = (max - min) * height;
+            if best.is_none_or(|(a, ..)| area < a) {
+                let (s, t) = ((min + max) / 2.0, bottom + height / 2.0);
+                let center = [s * u[0] + t * v[0], s * u[1] + t * v[1]];
+                best = Some((area, u[1].atan2(u[0]), center, max - min, height));
+            }
+        }
+
+        best.map(|(_, angle, center, length, width)| {
+            Obb::new(
+                Point::new(T::ONE * center[0], T::ONE * center[1]),
+                Radian::new(angle),
+                T::ONE * length,
+                T::ONE * width,
+            )
+        })
+    }
+}
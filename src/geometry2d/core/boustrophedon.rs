@@ -0,0 +1,263 @@
+use super::boolean::{rings_of, simplify, tolerance, Edge};
+use super::*;
+use std::ops::{Div, DivAssign, Mul};
+
+use sity::*;
+
+//-------------------------------------------------- Decomposition --------------------------------------------------
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Splits the polygon into boustrophedon cells for passes along `direction`: every line
+    /// parallel to `direction` crosses a cell along a single segment, so each cell can be covered
+    /// back and forth without leaving it. There is no cell when `direction` is zero.
+    pub fn boustrophedon_cells(&self, direction: &Vector<T>) -> Vec<Polygon<T>> {
+        let v = direction.to_value();
+        let l = (v.dx * v.dx + v.dy * v.dy).sqrt();
+        if l == 0.0 || !l.is_finite() {
+            return vec![];
+        }
+        let (c, s) = (v.dx / l, v.dy / l);
+
+        // Work in the frame where the passes are horizontal
+        let rings = rings_of(&MultiPolygon::from(self.clone()))
+            .iter()
+            .map(|ring| {
+                Polygon::new_unchecked(
+                    ring.points()
+                        .iter()
+                        .map(|p| Point::new(c * p.x + s * p.y, -s * p.x + c * p.y))
+                        .collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let eps = tolerance(&rings, &[]);
+
+        decompose(&rings, eps)
+            .into_iter()
+            .map(|cell| {
+                Polygon::new_unchecked(
+                    simplify(cell, eps)
+                        .points()
+                        .iter()
+                        .map(|p| {
+                            Point::new(T::ONE * (c * p.x - s * p.y), T::ONE * (s * p.x + c * p.y))
+                        })
+                        .collect(),
+                )
+            })
+            .filter(|cell| cell.points().len() >= 3)
+            .collect()
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    /// Splits the polygon into boustrophedon cells for passes along `direction`.
+    pub fn boustrophedon_cells(&self, direction: &Vector<T>) -> Vec<Polygon<T>> {
+        PolygonWithHoles::from(self.clone()).boustrophedon_cells(direction)
+    }
+}
+
+//-------------------------------------------------- Path --------------------------------------------------
+
+impl<T> Path<T>
+where
+    T: Number + AngleFactory,
+    T: HasValue<Output = f64>,
+    //
+    <T as HasValue>::Output: AngleOps,
+    <T as HasValue>::Output: FromValue<usize>,
+    <T as HasValue>::Output: Mul<T, Output = T>,
+    <T as HasValue>::Output: Mul<<T as Pow2>::Output, Output = <T as Pow2>::Output>,
+    //
+    T: std::ops::Neg<Output = T>,
+    //
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul<T, Output = <T as Pow2>::Output>,
+    T: Mul<<<T as Pow2>::Output as Div>::Output, Output = T>,
+    T: Mul<<T as Pow2>::Output, Output = <T as Pow3>::Output>,
+    //
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: DivAssign<<T as HasValue>::Output>,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number + AngleFactory,
+    <T as Pow2>::Output: Div,
+    <T as Pow2>::Output: Root2<Output = T>,
+    <T as Pow2>::Output: HasValue<Output = <T as HasValue>::Output>,
+    <T as Pow2>::Output: Mul<T, Output = <T as Pow3>::Output>,
+    <<T as Pow2>::Output as Div>::Output: Number,
+    //
+    T: Pow3,
+    <T as Pow3>::Output: Div<<T as Pow2>::Output, Output = T>,
+    <T as Pow3>::Output: Mul<T, Output = <T as Pow4>::Output>,
+    //
+    T: Pow4,
+    <T as Pow4>::Output: Root2<Output = <T as Pow2>::Output>,
+{
+    /// Covers each boustrophedon cell of `polygon` with parallel passes, visiting next the cell
+    /// with the closest pass, and joins everything with Dubins trajectories.
+    pub fn boustrophedon_mapping(
+        start: &Pose<T>,
+        direction: &Vector<T>,
+        radius: T,
+        distance: T,
+        polygon: &PolygonWithHoles<T>,
+    ) -> Option<Self> {
+        let mut cells = polygon
+            .boustrophedon_cells(direction)
+            .iter()
+            .map(|cell| cell.mapping(direction, distance))
+            .filter(|segments| !segments.is_empty())
+            .collect::<Vec<_>>();
+        if cells.is_empty() {
+            return None;
+        }
+
+        let mut poses = vec![start.clone()];
+        while !cells.is_empty() {
+            let position = poses.last().unwrap().position().clone();
+            let segments = Self::closest_cell(&mut cells, &position);
+            Self::push_passes(&mut poses, &segments);
+        }
+
+        Self::create(radius, &poses)
+    }
+}
+
+//-------------------------------------------------- Internals --------------------------------------------------
+
+/// A slice of the polygon between two consecutive vertex heights, bounded by two edges.
+struct Trapezoid {
+    bottom: f64,
+    top: f64,
+    left: Edge,
+    right: Edge,
+}
+
+impl Trapezoid {
+    fn x(edge: &Edge, y: f64) -> f64 {
+        let (a, b) = edge;
+        a.x + (b.x - a.x) * (y - a.y) / (b.y - a.y)
+    }
+
+    fn left_at(&self, y: f64) -> f64 {
+        Self::x(&self.left, y)
+    }
+
+    fn right_at(&self, y: f64) -> f64 {
+        Self::x(&self.right, y)
+    }
+}
+
+/// Returns the cells (counter-clockwise rings) of the oriented rings, for horizontal passes.
+fn decompose(rings: &[Polygon<f64>], eps: f64) -> Vec<Polygon<f64>> {
+    let mut heights = rings
+        .iter()
+        .flat_map(|ring| ring.points())
+        .map(|p| p.y)
+        .collect::<Vec<_>>();
+    heights.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    heights.dedup_by(|a, b| (*a - *b).abs() <= eps);
+
+    let edges = rings
+        .iter()
+        .flat_map(|ring| ring.segments())
+        .map(|seg| (seg.first().clone(), seg.second().clone()))
+        .collect::<Vec<_>>();
+
+    // The cells, as stacks of trapezoids, and the cell of each trapezoid of the previous slab
+    let mut cells: Vec<Vec<Trapezoid>> = vec![];
+    let mut previous: Vec<usize> = vec![];
+    for w in heights.windows(2) {
+        let (bottom, top) = (w[0], w[1]);
+        let y = (bottom + top) / 2.0;
+        let mut crossing = edges
+            .iter()
+            .filter(|(a, b)| (a.y <= y) != (b.y <= y))
+            .map(|edge| (Trapezoid::x(edge, y), edge.clone()))
+            .collect::<Vec<_>>();
+        crossing.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        let slab = crossing
+            .chunks_exact(2)
+            .map(|pair| Trapezoid {
+                bottom,
+                top,
+                left: pair[0].1.clone(),
+                right: pair[1].1.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        // Trapezoids of the previous slab touching each new trapezoid
+        let touching = |t: &Trapezoid, cell: usize| {
+            let below = cells[cell].last().unwrap();
+            below.top == bottom
+                && t.left_at(bottom).max(below.left_at(bottom)) + eps
+                    < t.right_at(bottom).min(below.right_at(bottom))
+        };
+        let links = slab
+            .iter()
+            .map(|t| {
+                previous
+                    .iter()
+                    .copied()
+                    .filter(|cell| touching(t, *cell))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut current = vec![];
+        for (t, below) in slab.into_iter().zip(links.iter()) {
+            let single = below.len() == 1
+                && links
+                    .iter()
+                    .filter(|other| other.contains(&below[0]))
+                    .count()
+                    == 1;
+            if single {
+                cells[below[0]].push(t);
+                current.push(below[0]);
+            } else {
+                cells.push(vec![t]);
+                current.push(cells.len() - 1);
+            }
+        }
+        previous = current;
+    }
+
+    cells
+        .iter()
+        .map(|stack| {
+            // Up the right side, then down the left side
+            let mut ring = vec![];
+            for t in stack.iter() {
+                ring.push(Point::new(t.right_at(t.bottom), t.bottom));
+                ring.push(Point::new(t.right_at(t.top), t.top));
+            }
+            for t in stack.iter().rev() {
+                ring.push(Point::new(t.left_at(t.top), t.top));
+                ring.push(Point::new(t.left_at(t.bottom), t.bottom));
+            }
+            let same = |a: &Point<f64>, b: &Point<f64>| {
+                (a.x - b.x).abs() <= eps && (a.y - b.y).abs() <= eps
+            };
+            ring.dedup_by(|a, b| same(a, b));
+            if ring.len() > 1 && same(&ring[0], &ring[ring.len() - 1]) {
+                ring.pop();
+            }
+            Polygon::new_unchecked(ring)
+        })
+        .collect()
+}
@@ -63,6 +63,23 @@ where
     }
 }
 
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for Circle<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self {
+            center: transform.apply_to_point(&self.center),
+            radius: self.radius,
+        }
+    }
+}
+
 //-------------------------------------------------- Ops --------------------------------------------------
 
 //------------------------- Add -------------------------
@@ -198,94 +215,250 @@ where
     }
 }
 
+//-------------------------------------------------- Intersection --------------------------------------------------
+
+/// The result of intersecting a circle or an arc with another shape.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CircleIntersection<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    /// The shapes do not meet.
+    None,
+    /// The shapes touch at a single point without crossing.
+    Tangent(Point<T>),
+    /// The shapes cross at a single point (the other crossing is outside a segment or an arc).
+    One(Point<T>),
+    /// The shapes cross at two points.
+    Two(Point<T>, Point<T>),
+    /// The two circles are the same.
+    Coincident,
+    /// The two arcs lie on the same circle and share these arcs.
+    Overlap(Vec<DirectedArc<T>>),
+}
+
+/// The crossings of two circles or of a line and a circle, in values.
+pub(super) enum Crossing {
+    None,
+    Tangent([f64; 2]),
+    Two([f64; 2], [f64; 2]),
+    Coincident,
+}
+
+/// Relative tolerance on the distances when telling tangent and coincident cases apart.
+pub(super) const TOLERANCE: f64 = 1e-9;
+
+impl Crossing {
+    /// Crossings of the circles `c1`, `r1` and `c2`, `r2`.
+    pub(super) fn circles(c1: [f64; 2], r1: f64, c2: [f64; 2], r2: f64) -> Self {
+        let v = [c2[0] - c1[0], c2[1] - c1[1]];
+        let d = v[0].hypot(v[1]);
+        let eps = TOLERANCE * r1.max(r2).max(d).max(1.0);
+        if d <= eps && (r1 - r2).abs() <= eps {
+            return Crossing::Coincident;
+        }
+        if d <= eps || d > r1 + r2 + eps || d < (r1 - r2).abs() - eps {
+            return Crossing::None;
+        }
+        let (u, n) = ([v[0] / d, v[1] / d], [-v[1] / d, v[0] / d]);
+        let a = (r1 * r1 - r2 * r2 + d * d) / (2.0 * d);
+        let foot = [c1[0] + a * u[0], c1[1] + a * u[1]];
+        if (d - r1 - r2).abs() <= eps || (d - (r1 - r2).abs()).abs() <= eps {
+            return Crossing::Tangent(foot);
+        }
+        let h = (r1 * r1 - a * a).max(0.0).sqrt();
+        Crossing::Two(
+            [foot[0] - h * n[0], foot[1] - h * n[1]],
+            [foot[0] + h * n[0], foot[1] + h * n[1]],
+        )
+    }
+
+    /// Crossings of the line through `a` along `u` with the circle `c`, `r`,
+    /// in increasing order of the parameter along `u`.
+    pub(super) fn line(a: [f64; 2], u: [f64; 2], c: [f64; 2], r: f64) -> Self {
+        let l = u[0].hypot(u[1]);
+        if l == 0.0 {
+            return Crossing::None;
+        }
+        let d = [u[0] / l, u[1] / l];
+        let f = [c[0] - a[0], c[1] - a[1]];
+        let t = f[0] * d[0] + f[1] * d[1];
+        let foot = [a[0] + t * d[0], a[1] + t * d[1]];
+        let distance = (f[0] * d[1] - f[1] * d[0]).abs();
+        let eps = TOLERANCE * r.max(distance).max(1.0);
+        if distance > r + eps {
+            Crossing::None
+        } else if (distance - r).abs() <= eps {
+            Crossing::Tangent(foot)
+        } else {
+            let h = (r * r - distance * distance).sqrt();
+            Crossing::Two(
+                [foot[0] - h * d[0], foot[1] - h * d[1]],
+                [foot[0] + h * d[0], foot[1] + h * d[1]],
+            )
+        }
+    }
+
+    /// Keeps the points satisfying `keep`, as an intersection.
+    pub(super) fn filter<T, F>(self, keep: F) -> CircleIntersection<T>
+    where
+        T: Number,
+        T: HasValue<Output = f64>,
+        T: Mul<<T as HasValue>::Output, Output = T>,
+        F: Fn([f64; 2]) -> bool,
+    {
+        let point = |p: [f64; 2]| Point::new(T::ONE * p[0], T::ONE * p[1]);
+        match self {
+            Crossing::None => CircleIntersection::None,
+            Crossing::Coincident => CircleIntersection::Coincident,
+            Crossing::Tangent(p) if keep(p) => CircleIntersection::Tangent(point(p)),
+            Crossing::Tangent(_) => CircleIntersection::None,
+            Crossing::Two(p, q) => match (keep(p), keep(q)) {
+                (true, true) => CircleIntersection::Two(point(p), point(q)),
+                (true, false) => CircleIntersection::One(point(p)),
+                (false, true) => CircleIntersection::One(point(q)),
+                (false, false) => CircleIntersection::None,
+            },
+        }
+    }
+}
+
+/// Returns `true` when `p` lies between `a` and `b`, knowing it is on their line.
+pub(super) fn within_segment(p: [f64; 2], a: [f64; 2], b: [f64; 2]) -> bool {
+    let u = [b[0] - a[0], b[1] - a[1]];
+    let l2 = u[0] * u[0] + u[1] * u[1];
+    let t = ((p[0] - a[0]) * u[0] + (p[1] - a[1]) * u[1]) / l2;
+    let eps = TOLERANCE / l2.sqrt().max(TOLERANCE);
+    (-eps..=1.0 + eps).contains(&t)
+}
+
+impl<T> Circle<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    pub(super) fn values(&self) -> ([f64; 2], f64) {
+        (
+            [self.center.x.value(), self.center.y.value()],
+            self.radius.value(),
+        )
+    }
+
+    pub fn intersection(&self, other: &Circle<T>) -> CircleIntersection<T> {
+        let ((c1, r1), (c2, r2)) = (self.values(), other.values());
+        Crossing::circles(c1, r1, c2, r2).filter(|_| true)
+    }
+
+    pub fn intersection_to_line(&self, other: &Line<T>) -> CircleIntersection<T> {
+        let (c, r) = self.values();
+        let (p, v) = (other.point(), other.vector());
+        Crossing::line(
+            [p.x.value(), p.y.value()],
+            [v.dx.value(), v.dy.value()],
+            c,
+            r,
+        )
+        .filter(|_| true)
+    }
+
+    /// The points are sorted from the first point of the segment.
+    pub fn intersection_to_segment(&self, other: &Segment<T>) -> CircleIntersection<T> {
+        let (c, r) = self.values();
+        let a = [other.first().x.value(), other.first().y.value()];
+        let b = [other.second().x.value(), other.second().y.value()];
+        Crossing::line(a, [b[0] - a[0], b[1] - a[1]], c, r).filter(|p| within_segment(p, a, b))
+    }
+}
+
 //-------------------------------------------------- Tangent --------------------------------------------------
-/**
- * return external and internal tangent points if exists
- */
-// impl<T> Circle<T>
-// where
-//     T: Number,
-//     T: Mul<T>,
-//     <T as Mul>::Output: Number,
-//     T: Pow2,
-//     <T as Pow2>::Output: Number,
-//     <T as Pow2>::Output: Root2<Output = T>,
-//     <T as Mul>::Output: Div<T, Output = T>,
-//     T: Div<T>,
-//     <T as Div<T>>::Output: AngleFactory,
-//     <T as Div<T>>::Output: HasValue<Output = <T as HasValue>::Output>,
-//     <T as HasValue>::Output: AngleOps,
-//     T: Mul<<T as HasValue>::Output, Output = T>,
-// {
-//     pub fn tangents(
-//         &self,
-//         other: &Circle<T>,
-//     ) -> (
-//         Option<(Segment<T>, Segment<T>)>,
-//         Option<(Segment<T>, Segment<T>)>,
-//     ) {
-//         let d = self.center.distance(&other.center);
-
-//         // External exists
-//         if d >= (self.radius() - other.radius()).abs() {
-//             let teta = ((other.center().y - self.center().y)
-//                 / (other.center().x - self.center().x))
-//                 .atan();
-//             println!("teta = {}", teta.to_degrees());
-//             let delta = ((other.radius() - self.radius()) / d).asin();
-//             println!("delta = {}", delta.to_degrees());
-//             // first
-//             let alpha = teta + delta;
-//             println!("alpha = {}", alpha.to_degrees());
-//             let x = self.center().x + self.radius * alpha.cos();
-//             let y = self.center().y + self.radius * alpha.sin();
-//             let p1 = Point::new(x, y);
-//             let x = other.center().x + other.radius * (alpha + Radian::PI).cos();
-//             let y = other.center().y + other.radius * (alpha + Radian::PI).sin();
-//             let p2 = Point::new(x, y);
-//             let first = Segment::new(p1, p2);
-//             // second
-//             let alpha = teta - delta;
-//             println!("alpha = {}", alpha.to_degrees());
-//             let x = self.center().x + self.radius * alpha.cos();
-//             let y = self.center().y + self.radius * alpha.sin();
-//             let p1 = Point::new(x, y);
-//             let x = other.center().x + other.radius * (alpha + Radian::PI).cos();
-//             let y = other.center().y + other.radius * (alpha + Radian::PI).sin();
-//             let p2 = Point::new(x, y);
-//             let second = Segment::new(p1, p2);
-//             let external = (first, second);
-//             // Internal exists
-//             if d >= self.radius() + other.radius() {
-//                 let delta = ((self.radius() + other.radius()) / d).asin();
-//                 // first
-//                 let alpha = teta + delta;
-//                 let x = self.center().x + self.radius * alpha.cos();
-//                 let y = self.center().y + self.radius * alpha.sin();
-//                 let p1 = Point::new(x, y);
-//                 let x = other.center().x + other.radius * (alpha + Radian::PI).cos();
-//                 let y = other.center().y + other.radius * (alpha + Radian::PI).sin();
-//                 let p2 = Point::new(x, y);
-//                 let first = Segment::new(p1, p2);
-//                 // second
-//                 let alpha = teta - delta;
-//                 let x = self.center().x + self.radius * alpha.cos();
-//                 let y = self.center().y + self.radius * alpha.sin();
-//                 let p1 = Point::new(x, y);
-//                 let x = other.center().x + other.radius * (alpha + Radian::PI).cos();
-//                 let y = other.center().y + other.radius * (alpha + Radian::PI).sin();
-//                 let p2 = Point::new(x, y);
-//                 let second = Segment::new(p1, p2);
-//                 let internal = (first, second);
-//                 (Some(external), Some(internal))
-//             } else {
-//                 (Some(external), None)
-//             }
-//         } else {
-//             (None, None)
-//         }
-//     }
-// }
+/// The common tangents of two circles, each a segment from the tangent point on the first
+/// circle to the tangent point on the second one.
+///
+/// The first segment of each pair is on the left of the line joining the centers, looking from
+/// the first circle to the second one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommonTangents<T: Number> {
+    /// The circles are the same: every tangent is common.
+    Coincident,
+    /// One circle is strictly inside the other: there is no common tangent.
+    Nested,
+    /// One circle touches the other from inside: the only common tangent touches both at this point.
+    InternallyTangent(Point<T>),
+    /// The circles cross: only the external tangents exist.
+    Intersecting { external: (Segment<T>, Segment<T>) },
+    /// The circles touch from outside: the internal tangents merge into the tangent at this point.
+    ExternallyTangent {
+        external: (Segment<T>, Segment<T>),
+        contact: Point<T>,
+    },
+    /// The circles are apart: all four tangents exist.
+    Separate {
+        external: (Segment<T>, Segment<T>),
+        internal: (Segment<T>, Segment<T>),
+    },
+}
+
+impl<T> Circle<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Returns the common tangents of two circles of any radii.
+    pub fn tangents(&self, other: &Circle<T>) -> CommonTangents<T> {
+        let ((c1, r1), (c2, r2)) = (self.values(), other.values());
+        let v = [c2[0] - c1[0], c2[1] - c1[1]];
+        let d = v[0].hypot(v[1]);
+        let eps = TOLERANCE * r1.max(r2).max(d).max(1.0);
+        let point = |p: [f64; 2]| Point::new(T::ONE * p[0], T::ONE * p[1]);
+        let at = |c: [f64; 2], r: f64, n: [f64; 2]| point([c[0] + r * n[0], c[1] + r * n[1]]);
+
+        if d <= eps && (r1 - r2).abs() <= eps {
+            return CommonTangents::Coincident;
+        }
+        if d < (r1 - r2).abs() - eps {
+            return CommonTangents::Nested;
+        }
+        let u = [v[0] / d, v[1] / d];
+        if (d - (r1 - r2).abs()).abs() <= eps {
+            let side = if r1 >= r2 { 1.0 } else { -1.0 };
+            return CommonTangents::InternallyTangent(at(c1, r1, [side * u[0], side * u[1]]));
+        }
+
+        // Unit normals `n` of the tangents, with `n . u = k`, on the left then on the right
+        let normals = |k: f64| {
+            let (cos, sin) = (k, (1.0 - k * k).max(0.0).sqrt());
+            [1.0, -1.0].map(|side| {
+                [
+                    cos * u[0] - side * sin * u[1],
+                    cos * u[1] + side * sin * u[0],
+                ]
+            })
+        };
+        let [left, right] = normals((r1 - r2) / d);
+        let external = (
+            Segment::new(at(c1, r1, left), at(c2, r2, left)),
+            Segment::new(at(c1, r1, right), at(c2, r2, right)),
+        );
+        if d < r1 + r2 - eps {
+            return CommonTangents::Intersecting { external };
+        }
+        if d <= r1 + r2 + eps {
+            return CommonTangents::ExternallyTangent {
+                external,
+                contact: at(c1, r1, u),
+            };
+        }
+        let [left, right] = normals((r1 + r2) / d);
+        let internal = (
+            Segment::new(at(c1, r1, left), at(c2, -r2, left)),
+            Segment::new(at(c1, r1, right), at(c2, -r2, right)),
+        );
+        CommonTangents::Separate { external, internal }
+    }
+}
 
 impl<T> Circle<T>
 where
@@ -390,125 +563,6 @@ where
     }
 }
 
-// impl<T> Circle<T>
-// where
-//     // distance
-//     T: Number,
-//     T: Pow2,
-//     <T as Pow2>::Output: Number,
-//     T: Mul<T, Output = <T as Pow2>::Output>,
-//     <T as Mul>::Output: Number,
-//     <T as Pow2>::Output: Root2<Output = T>,
-//     // o
-//     T: Pow3,
-//     <T as Pow2>::Output: Mul<T, Output = <T as Pow3>::Output>,
-//     T: Pow4,
-//     <T as Pow3>::Output: Mul<T, Output = <T as Pow4>::Output>,
-//     <T as Pow4>::Output: Root2<Output = <T as Pow2>::Output>,
-//     // xa
-//     T: Div<<T as HasValue>::Output, Output = T>,
-//     T: Mul<<T as Pow2>::Output, Output = <T as Pow3>::Output>,
-//     <T as HasValue>::Output: Mul<<T as Pow2>::Output, Output = <T as Pow2>::Output>,
-//     <T as Pow3>::Output: Div<<T as Pow2>::Output, Output = T>,
-//     // xb
-//     <T as HasValue>::Output: Mul<T, Output = T>,
-// {
-//     pub fn internal_tangents(&self, other: &Circle<T>) -> Option<(Segment<T>, Segment<T>)> {
-//         let d = self.center.distance(&other.center);
-//         // External exists
-//         if d > (self.radius() - other.radius()).abs() {
-//             let two = (T::ONE + T::ONE).value();
-//             let four = two + two;
-//             let quarter = (T::ONE).value() / four;
-//             let l = (d.pow2() - (self.radius + other.radius()).pow2()).root2();
-//             let r = (l.pow2() + other.radius.pow2()).root2();
-//             let o = quarter
-//                 * ((d + self.radius + r)
-//                     * (d + self.radius - r)
-//                     * (d - self.radius + r)
-//                     * (self.radius + r - d))
-//                     .root2();
-//             //
-//             // x1
-//             let a = (self.center.x + other.center.x) / two;
-//             let b = ((other.center.x - self.center.x) * (self.radius.pow2() - r.pow2())
-//                 / (two * d.pow2()));
-//             let c = two * (self.center.y - other.center.y) * o / d.pow2();
-//             let x1 = a + b + c;
-//             // y1
-//             let a = (self.center.y + other.center.y) / two;
-//             let b = ((other.center.y - self.center.y) * (self.radius.pow2() - r.pow2())
-//                 / (two * d.pow2()));
-//             let c = two * (self.center.x - other.center.x) * o / d.pow2();
-//             let y1 = a + b + c;
-//             let pt11 = Point::new(x1, y1);
-//             println!("pt1a = {}", pt11);
-//             //
-//             let x1 = a + b - c;
-//             let y1 = a + b - c;
-//             let pt12 = Point::new(x1, y1);
-//             println!("pt1a = {}", pt12);
-//             // -----
-
-//             // -----
-//             None
-//         } else {
-//             None
-//         }
-//     }
-// }
-
-// impl<T> Circle<T>
-// where
-//     T: Number,
-//     T: Mul<T>,
-//     <T as Mul>::Output: Number,
-//     T: Pow2,
-//     <T as Pow2>::Output: Number,
-//     <T as Pow2>::Output: Root2<Output = T>,
-//     <T as Mul>::Output: Div<T, Output = T>,
-//     T: Div<T>,
-//     <T as Div<T>>::Output: AngleFactory,
-//     <T as Div<T>>::Output: HasValue<Output = <T as HasValue>::Output>,
-//     <T as HasValue>::Output: AngleOps,
-//     T: Mul<<T as HasValue>::Output, Output = T>,
-// {
-//     pub fn external_tangents(&self, other: &Circle<T>) -> Option<(Segment<T>, Segment<T>)> {
-//         let d = self.center.distance(&other.center);
-
-//         // Internal exists
-//         if d > self.radius() + other.radius() {
-//             let teta = ((other.center().y - self.center().y)
-//                 / (other.center().x - self.center().x))
-//                 .atan();
-//             let delta = ((other.radius() + self.radius()) / d).asin();
-//             // first
-//             let alpha = teta + delta;
-//             let x = self.center().x + self.radius * alpha.cos();
-//             let y = self.center().y + self.radius * alpha.sin();
-//             let p1 = Point::new(x, y);
-//             let x = self.center().x + self.radius * (alpha.cos() + <T as HasValue>::Output::PI);
-//             let y = self.center().y + self.radius * (alpha.sin() + <T as HasValue>::Output::PI);
-//             let p2 = Point::new(x, y);
-//             let first = Segment::new(p1, p2);
-//             // second
-//             let alpha = teta - delta;
-//             let x = self.center().x + self.radius * alpha.cos();
-//             let y = self.center().y + self.radius * alpha.sin();
-//             let p1 = Point::new(x, y);
-//             let x = self.center().x + self.radius * (alpha.cos() + <T as HasValue>::Output::PI);
-//             let y = self.center().y + self.radius * (alpha.sin() + <T as HasValue>::Output::PI);
-//             let p2 = Point::new(x, y);
-//             let second = Segment::new(p1, p2);
-//             let internal = (first, second);
-//             Some(internal)
-//         } else {
-//             None
-//         }
-//         todo!()
-//     }
-// }
-
 //-------------------------------------------------- Display --------------------------------------------------
 
 impl<T: Number> Display for Circle<T> {
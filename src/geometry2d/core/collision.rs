@@ -0,0 +1,324 @@
+use super::*;
+use std::ops::Mul;
+
+use sity::*;
+
+//-------------------------------------------------- Collision --------------------------------------------------
+
+/// The first point where a moving element meets an obstacle.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Contact<T: Number> {
+    pub point: Point<T>,
+    /// The distance travelled along the element before the contact.
+    pub position: T,
+}
+
+impl<T: Number> Contact<T> {
+    pub fn new(point: Point<T>, position: T) -> Self {
+        Self { point, position }
+    }
+}
+
+/// Intersection and clearance queries between an element driven from its start and an obstacle.
+pub trait Collision<T, O>
+where
+    T: Number,
+{
+    /// Returns the first contact with `other` (entering it or touching its boundary), `None`
+    /// when they do not meet. An element starting inside `other` touches it at position zero.
+    fn first_contact(&self, other: &O) -> Option<Contact<T>>;
+
+    /// Returns `true` when the element meets `other`.
+    fn collides(&self, other: &O) -> bool {
+        self.first_contact(other).is_some()
+    }
+
+    /// Returns the smallest distance between the element and `other`, zero when they meet.
+    fn clearance(&self, other: &O) -> T;
+}
+
+impl<T, O> Collision<T, O> for Segment<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    O: Clone + Into<Obstacle<T>>,
+{
+    fn first_contact(&self, other: &O) -> Option<Contact<T>> {
+        first_contact(&[Piece::from_segment(self)], other)
+    }
+
+    fn clearance(&self, other: &O) -> T {
+        clearance(&[Piece::from_segment(self)], other)
+    }
+}
+
+impl<T, O> Collision<T, O> for DirectedArc<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    O: Clone + Into<Obstacle<T>>,
+{
+    fn first_contact(&self, other: &O) -> Option<Contact<T>> {
+        first_contact(&[Piece::from_arc(self)], other)
+    }
+
+    fn clearance(&self, other: &O) -> T {
+        clearance(&[Piece::from_arc(self)], other)
+    }
+}
+
+impl<T, O> Collision<T, O> for Trajectory<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    O: Clone + Into<Obstacle<T>>,
+{
+    fn first_contact(&self, other: &O) -> Option<Contact<T>> {
+        first_contact(&Piece::from_trajectory(self), other)
+    }
+
+    fn clearance(&self, other: &O) -> T {
+        clearance(&Piece::from_trajectory(self), other)
+    }
+}
+
+impl<T, O> Collision<T, O> for Path<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    O: Clone + Into<Obstacle<T>>,
+{
+    fn first_contact(&self, other: &O) -> Option<Contact<T>> {
+        first_contact(&Piece::from_path(self), other)
+    }
+
+    fn clearance(&self, other: &O) -> T {
+        clearance(&Piece::from_path(self), other)
+    }
+}
+
+//-------------------------------------------------- Internals --------------------------------------------------
+
+fn first_contact<T, O>(pieces: &[Piece], other: &O) -> Option<Contact<T>>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    O: Clone + Into<Obstacle<T>>,
+{
+    let obstacle = other.clone().into().to_value();
+    let mut offset = 0.0;
+    for piece in pieces {
+        if let Some((s, p)) = piece.first_contact(&obstacle) {
+            return Some(Contact::new(
+                Point::new(T::ONE * p.x, T::ONE * p.y),
+                T::ONE * (offset + s),
+            ));
+        }
+        offset += piece.length();
+    }
+    None
+}
+
+fn clearance<T, O>(pieces: &[Piece], other: &O) -> T
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    O: Clone + Into<Obstacle<T>>,
+{
+    let obstacle = other.clone().into().to_value();
+    if pieces
+        .iter()
+        .any(|piece| piece.first_contact(&obstacle).is_some())
+    {
+        return T::ZERO;
+    }
+    let d = pieces
+        .iter()
+        .map(|piece| piece.clearance(&obstacle))
+        .fold(f64::INFINITY, f64::min);
+    T::ONE * d
+}
+
+/// Points closer than `eps` to the boundary are contained.
+fn contains(obstacle: &Obstacle<f64>, p: &Point<f64>, eps: f64) -> bool {
+    match obstacle {
+        Obstacle::Polygon(polygon) => polygon.classify(p, eps) != PointPosition::Outside,
+        Obstacle::Circle(circle) => circle.contains(p),
+    }
+}
+
+/// Largest coordinate of the obstacle, in absolute value.
+fn extent(obstacle: &Obstacle<f64>) -> f64 {
+    match obstacle {
+        Obstacle::Polygon(polygon) => polygon.points().iter().map(magnitude).fold(0.0, f64::max),
+        Obstacle::Circle(circle) => magnitude(circle.center()) + circle.radius(),
+    }
+}
+
+fn magnitude(p: &Point<f64>) -> f64 {
+    p.x.abs().max(p.y.abs())
+}
+
+fn points(intersection: CircleIntersection<f64>) -> Vec<Point<f64>> {
+    match intersection {
+        CircleIntersection::Tangent(p) | CircleIntersection::One(p) => vec![p],
+        CircleIntersection::Two(p, q) => vec![p, q],
+        _ => vec![],
+    }
+}
+
+/// A straight or circular piece of an element, in values.
+enum Piece {
+    Line(Segment<f64>),
+    Arc(DirectedArc<f64>),
+}
+
+impl Piece {
+    fn from_segment<T>(segment: &Segment<T>) -> Self
+    where
+        T: Number,
+        T: HasValue<Output = f64>,
+    {
+        Piece::Line(segment.to_value())
+    }
+
+    fn from_arc<T>(arc: &DirectedArc<T>) -> Self
+    where
+        T: Number,
+        T: HasValue<Output = f64>,
+    {
+        Piece::Arc(arc.to_value())
+    }
+
+    fn from_trajectory<T>(trajectory: &Trajectory<T>) -> Vec<Self>
+    where
+        T: Number,
+        T: HasValue<Output = f64>,
+    {
+        vec![
+            Self::from_arc(trajectory.start_rotation()),
+            match trajectory.transition() {
+                Transition::Segment(seg) => Self::from_segment(seg),
+                Transition::Arc(arc) => Self::from_arc(arc),
+            },
+            Self::from_arc(trajectory.finish_rotation()),
+        ]
+    }
+
+    fn from_path<T>(path: &Path<T>) -> Vec<Self>
+    where
+        T: Number,
+        T: HasValue<Output = f64>,
+    {
+        path.trajectories()
+            .iter()
+            .flat_map(Self::from_trajectory)
+            .collect()
+    }
+
+    fn length(&self) -> f64 {
+        match self {
+            Piece::Line(segment) => segment.length(),
+            Piece::Arc(arc) => arc.length().abs(),
+        }
+    }
+
+    fn start(&self) -> Point<f64> {
+        match self {
+            Piece::Line(segment) => segment.first().clone(),
+            Piece::Arc(arc) => arc.start_point(),
+        }
+    }
+
+    /// Largest coordinate of the piece, in absolute value.
+    fn extent(&self) -> f64 {
+        match self {
+            Piece::Line(segment) => magnitude(segment.first()).max(magnitude(segment.second())),
+            Piece::Arc(arc) => magnitude(arc.center()) + arc.radius(),
+        }
+    }
+
+    /// Returns the travelled distance to reach `p`, a point of the piece up to `eps`. Points of
+    /// an arc just before its start are reached at once, and points past its end are not reached.
+    fn travel(&self, p: &Point<f64>, eps: f64) -> Option<f64> {
+        match self {
+            Piece::Line(segment) => Some(segment.first().distance(p)),
+            Piece::Arc(arc) => {
+                let s = arc.circle().arc_between(&arc.start_point(), p).length();
+                if s <= self.length() + eps {
+                    Some(s)
+                } else if s >= 2.0 * std::f64::consts::PI * arc.radius() - eps {
+                    Some(0.0)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn distance(&self, p: &Point<f64>) -> f64 {
+        match self {
+            Piece::Line(segment) => p.distance(segment),
+            Piece::Arc(arc) => p.distance(arc),
+        }
+    }
+
+    /// Returns the travelled distance before meeting `obstacle`, and the point where it happens.
+    fn first_contact(&self, obstacle: &Obstacle<f64>) -> Option<(f64, Point<f64>)> {
+        // Relative tolerance, as for the intersections
+        let eps = TOLERANCE * self.extent().max(extent(obstacle)).max(1.0);
+        let start = self.start();
+        if contains(obstacle, &start, eps) {
+            return Some((0.0, start));
+        }
+        let candidates = match (self, obstacle) {
+            (Piece::Line(segment), Obstacle::Circle(circle)) => {
+                points(segment.intersection_to_circle(circle))
+            }
+            (Piece::Arc(arc), Obstacle::Circle(circle)) => {
+                points(arc.intersection_to_circle(circle))
+            }
+            (_, Obstacle::Polygon(polygon)) => {
+                // The vertices met also catch the edges overlapping the piece
+                let mut res = polygon
+                    .points()
+                    .iter()
+                    .filter(|v| self.distance(v) <= eps)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                for edge in polygon.segments() {
+                    match self {
+                        Piece::Line(segment) => res.extend(segment.intersection(&edge)),
+                        Piece::Arc(arc) => res.extend(points(arc.intersection_to_segment(&edge))),
+                    }
+                }
+                res
+            }
+        };
+        candidates
+            .into_iter()
+            .filter_map(|p| self.travel(&p, eps).map(|s| (s, p)))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+    }
+
+    /// Distance between the piece and `obstacle`, assuming they do not meet.
+    fn clearance(&self, obstacle: &Obstacle<f64>) -> f64 {
+        match obstacle {
+            Obstacle::Circle(circle) => (self.distance(circle.center()) - circle.radius()).max(0.0),
+            Obstacle::Polygon(polygon) => polygon
+                .segments()
+                .iter()
+                .map(|edge| match self {
+                    Piece::Line(segment) => segment.distance(edge),
+                    Piece::Arc(arc) => arc.distance(edge),
+                })
+                .fold(f64::INFINITY, f64::min),
+        }
+    }
+}
@@ -155,6 +155,24 @@ where
     }
 }
 
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for DirectedArc<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self {
+            circle: self.circle.transformed(transform),
+            start_angle: transform.apply_to_angle(self.start_angle),
+            delta_angle: self.delta_angle,
+        }
+    }
+}
+
 //-------------------------------------------------- Length --------------------------------------------------
 
 impl<T> DirectedArc<T>
@@ -168,6 +186,173 @@ where
     }
 }
 
+//-------------------------------------------------- Discretize --------------------------------------------------
+
+impl<T> DirectedArc<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    <T as HasValue>::Output: FromValue<usize>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Returns `segments + 1` points evenly spread along the arc, from its start to its finish.
+    pub fn discretize(&self, segments: usize) -> Vec<Point<T>> {
+        let n = <T as HasValue>::Output::from_value(segments.max(1));
+        (0..=segments)
+            .map(|i| {
+                let ratio = <T as HasValue>::Output::from_value(i) / n;
+                DirectedArc::new(
+                    self.circle.clone(),
+                    self.start_angle,
+                    self.delta_angle * ratio,
+                )
+                .finish_point()
+            })
+            .collect()
+    }
+}
+
+//-------------------------------------------------- Intersection --------------------------------------------------
+
+impl<T> DirectedArc<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Returns the counter-clockwise angular range `[low, low + sweep]` covered by the arc.
+    pub(super) fn range(&self) -> (f64, f64) {
+        let delta = self.delta_angle.value().abs();
+        match self.direction() {
+            Direction::CounterClockWise => (self.start_angle.value(), delta),
+            Direction::ClockWise => (self.start_angle.value() - delta, delta),
+        }
+    }
+
+    /// Returns `true` when `p`, a point of the circle, is on the arc.
+    fn covers(&self, p: [f64; 2]) -> bool {
+        let (low, sweep) = self.range();
+        let c = self.center();
+        let angle = (p[1] - c.y.value()).atan2(p[0] - c.x.value());
+        let phi = (angle - low).rem_euclid(2.0 * std::f64::consts::PI);
+        phi <= sweep + TOLERANCE || 2.0 * std::f64::consts::PI - phi <= TOLERANCE
+    }
+
+    /// Returns the crossings with `other`, restricted to the arc; `Coincident` when `other` is the
+    /// circle of the arc.
+    pub fn intersection_to_circle(&self, other: &Circle<T>) -> CircleIntersection<T> {
+        let ((c1, r1), (c2, r2)) = (self.circle.circle().values(), other.values());
+        Crossing::circles(c1, r1, c2, r2).filter(|p| self.covers(p))
+    }
+
+    pub fn intersection_to_line(&self, other: &Line<T>) -> CircleIntersection<T> {
+        let (c, r) = self.circle.circle().values();
+        let (p, v) = (other.point(), other.vector());
+        Crossing::line(
+            [p.x.value(), p.y.value()],
+            [v.dx.value(), v.dy.value()],
+            c,
+            r,
+        )
+        .filter(|p| self.covers(p))
+    }
+
+    /// The points are sorted from the first point of the segment.
+    pub fn intersection_to_segment(&self, other: &Segment<T>) -> CircleIntersection<T> {
+        let (c, r) = self.circle.circle().values();
+        let a = [other.first().x.value(), other.first().y.value()];
+        let b = [other.second().x.value(), other.second().y.value()];
+        Crossing::line(a, [b[0] - a[0], b[1] - a[1]], c, r)
+            .filter(|p| within_segment(p, a, b) && self.covers(p))
+    }
+
+    /// Returns the crossings of the two arcs. When they lie on the same circle, the shared
+    /// parts are returned as `Overlap`, oriented like `self`, or as `One` or `Two` when the arcs
+    /// only share end points.
+    pub fn intersection(&self, other: &DirectedArc<T>) -> CircleIntersection<T> {
+        let ((c1, r1), (c2, r2)) = (
+            self.circle.circle().values(),
+            other.circle.circle().values(),
+        );
+        match Crossing::circles(c1, r1, c2, r2) {
+            Crossing::Coincident => self.overlap(other),
+            crossing => crossing.filter(|p| self.covers(p) && other.covers(p)),
+        }
+    }
+
+    /// Shared parts of two arcs of the same circle.
+    fn overlap(&self, other: &DirectedArc<T>) -> CircleIntersection<T> {
+        let two_pi = 2.0 * std::f64::consts::PI;
+        let ((low, sweep), (other_low, other_sweep)) = (self.range(), other.range());
+        // Ranges of the other arc, relative to the start of the range of `self`
+        let offset = (other_low - low).rem_euclid(two_pi);
+        let mut shared = vec![];
+        for (from, to) in [
+            (offset, offset + other_sweep),
+            (offset - two_pi, offset + other_sweep - two_pi),
+        ] {
+            let (from, to) = (from.max(0.0), to.min(sweep));
+            if from <= to + TOLERANCE {
+                shared.push((from, to.max(from)));
+            }
+        }
+
+        let arcs = shared
+            .iter()
+            .map(|&(from, to)| {
+                let start = match self.direction() {
+                    Direction::CounterClockWise => low + from,
+                    Direction::ClockWise => low + to,
+                };
+                DirectedArc::new(
+                    self.circle.clone(),
+                    Radian::new(start),
+                    Radian::new(to - from),
+                )
+            })
+            .collect::<Vec<_>>();
+        // Pieces shorter than the tolerance are end points the arcs only touch at
+        let (arcs, ends): (Vec<_>, Vec<_>) = arcs
+            .into_iter()
+            .partition(|arc| arc.delta_angle.value() > TOLERANCE);
+        match (arcs.as_slice(), ends.as_slice()) {
+            ([], []) => CircleIntersection::None,
+            ([], [end]) => CircleIntersection::One(end.start_point()),
+            ([], [first, second, ..]) => {
+                CircleIntersection::Two(first.start_point(), second.start_point())
+            }
+            _ => CircleIntersection::Overlap(arcs),
+        }
+    }
+}
+
+//-------------------------------------------------- Distance --------------------------------------------------
+
+impl<T> Distance<T, Segment<T>> for DirectedArc<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Zero when they meet. Otherwise the closest points are an end of one of them, or the point
+    /// of the segment closest to the center, facing the arc.
+    fn distance(&self, other: &Segment<T>) -> T {
+        if self.intersection_to_segment(other) != CircleIntersection::None {
+            return T::ZERO;
+        }
+        let (arc, segment) = (self.to_value(), other.to_value());
+        let mut d = [arc.start_point(), arc.finish_point()]
+            .iter()
+            .map(|p| p.distance(&segment))
+            .chain([segment.first(), segment.second()].map(|p| p.distance(&arc)))
+            .fold(f64::INFINITY, f64::min);
+        if let Some(foot) = arc.center().projection_to_segment(&segment) {
+            d = d.min(foot.distance(&arc));
+        }
+        T::ONE * d
+    }
+}
+
 //-------------------------------------------------- Ops --------------------------------------------------
 
 //------------------------- Add -------------------------
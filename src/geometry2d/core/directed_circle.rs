@@ -184,6 +184,23 @@ where
     }
 }
 
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for DirectedCircle<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self {
+            circle: self.circle.transformed(transform),
+            direction: transform.apply_to_direction(self.direction),
+        }
+    }
+}
+
 //-------------------------------------------------- Contains --------------------------------------------------
 
 impl<T> Contains<Point<T>> for DirectedCircle<T>
@@ -201,7 +218,33 @@ where
     }
 }
 
-//-------------------------------------------------- Tangent --------------------------------------------------
+//-------------------------------------------------- Arc --------------------------------------------------
+
+impl<T> DirectedCircle<T>
+where
+    T: Number + AngleFactory,
+    <T as HasValue>::Output: AngleOps,
+    //
+    T: Pow2,
+    T: Mul<T, Output = <T as Pow2>::Output>,
+    <T as Pow2>::Output: Number + AngleFactory,
+    <T as Pow2>::Output: HasValue<Output = <T as HasValue>::Output>,
+{
+    /// Returns the arc going from `from` to `to` following the direction of the circle.
+    /// Both points are assumed to be on the circle.
+    pub fn arc_between(&self, from: &Point<T>, to: &Point<T>) -> DirectedArc<T> {
+        let start_angle = self.center().angle_from_point(from);
+        let mut delta_angle = if self.direction == Direction::CounterClockWise {
+            Point::angle_from_points(from, self.center(), to)
+        } else {
+            Point::angle_from_points(to, self.center(), from)
+        };
+        if delta_angle < Radian::ZERO {
+            delta_angle += Radian::PI + Radian::PI;
+        }
+        DirectedArc::new(self.clone(), start_angle, delta_angle)
+    }
+}
 
 //-------------------------------------------------- Tangent --------------------------------------------------
 
@@ -389,6 +432,59 @@ where
     }
 }
 
+impl<T> DirectedCircle<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Returns the common tangent leaving `self` and joining `other`, both driven in their
+    /// direction, whatever their radii.
+    pub fn tangent_to(&self, other: &DirectedCircle<T>) -> Option<Segment<T>> {
+        let candidates = match self.circle().tangents(other.circle()) {
+            CommonTangents::Intersecting { external } => vec![external.0, external.1],
+            CommonTangents::ExternallyTangent { external, .. } => vec![external.0, external.1],
+            CommonTangents::Separate { external, internal } => {
+                vec![external.0, external.1, internal.0, internal.1]
+            }
+            _ => vec![],
+        };
+        candidates.into_iter().find(|seg| {
+            // Moving along the segment must follow the direction of both circles
+            self.is_followed_by(seg.first(), seg) && other.is_followed_by(seg.second(), seg)
+        })
+    }
+
+    /// Returns the tangent leaving `self` in its direction and reaching `point`: it starts at
+    /// `point` itself when the point is on the circle, and does not exist when it is inside.
+    pub fn tangent_to_point(&self, point: &Point<T>) -> Option<Segment<T>> {
+        match self.circle().tangents(&Circle::new(point.clone(), T::ZERO)) {
+            CommonTangents::InternallyTangent(contact) => {
+                Some(Segment::new(contact, point.clone()))
+            }
+            CommonTangents::Separate { external, .. } => [external.0, external.1]
+                .into_iter()
+                .find(|seg| self.is_followed_by(seg.first(), seg)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` when moving along `segment` at `p`, on the circle, follows its direction.
+    fn is_followed_by(&self, p: &Point<T>, segment: &Segment<T>) -> bool {
+        let c = self.center();
+        let r = [p.x.value() - c.x.value(), p.y.value() - c.y.value()];
+        let v = [
+            segment.second().x.value() - segment.first().x.value(),
+            segment.second().y.value() - segment.first().y.value(),
+        ];
+        let turn = r[0] * v[1] - r[1] * v[0];
+        match self.direction() {
+            Direction::CounterClockWise => turn > 0.0,
+            Direction::ClockWise => turn < 0.0,
+        }
+    }
+}
+
 //-------------------------------------------------- Display --------------------------------------------------
 
 impl<T: Number + Display> Display for DirectedCircle<T> {
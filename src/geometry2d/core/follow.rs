@@ -89,7 +89,10 @@ where
             return FollowResult::new(pose, Some(complete_duration));
         }
         let delta = velocity * duration / l;
-        let angle = self.start_angle() + self.delta_angle() * delta.value();
+        let angle = match self.direction() {
+            Direction::CounterClockWise => self.start_angle() + self.delta_angle() * delta.value(),
+            Direction::ClockWise => self.start_angle() - self.delta_angle() * delta.value(),
+        };
         //
         let x = self.center().x + self.radius() * angle.cos();
         let y = self.center().y + self.radius() * angle.sin();
@@ -100,6 +103,36 @@ where
     }
 }
 
+//-------------------------------------------------- Transition --------------------------------------------------
+
+impl<T, V, S> Follow<T, V, S> for Transition<T>
+where
+    T: Number,
+    V: Number,
+    S: Number,
+    T: Div<V, Output = S>,
+    V: Mul<S, Output = T>,
+    //
+    T: Neg<Output = T>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: Div<T>,
+    <T as Div<T>>::Output: HasValue<Output = <T as HasValue>::Output>,
+    //
+    <T as HasValue>::Output: AngleOps,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+{
+    fn follow(&self, velocity: V, duration: S) -> FollowResult<T, S> {
+        match self {
+            Transition::Segment(seg) => seg.follow(velocity, duration),
+            Transition::Arc(arc) => arc.follow(velocity, duration),
+        }
+    }
+}
+
 //-------------------------------------------------- Trajectory --------------------------------------------------
 
 impl<T, V, S> Follow<T, V, S> for Trajectory<T>
@@ -132,8 +165,8 @@ where
             }
             None => return res,
         }
-        // Segement
-        let res = self.segment().follow(velocity, duration - total);
+        // Transition
+        let res = self.transition().follow(velocity, duration - total);
         match res.complete {
             Some(d) => {
                 total += d;
@@ -191,3 +224,77 @@ where
         last.follow(velocity, t)
     }
 }
+
+//-------------------------------------------------- ReedsShepp --------------------------------------------------
+
+impl<T, V, S> Follow<T, V, S> for ReedsSheppElement<T>
+where
+    T: Number,
+    V: Number,
+    S: Number,
+    T: Div<V, Output = S>,
+    V: Mul<S, Output = T>,
+    //
+    T: Neg<Output = T>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: Div<T>,
+    <T as Div<T>>::Output: HasValue<Output = <T as HasValue>::Output>,
+    //
+    <T as HasValue>::Output: AngleOps,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+{
+    fn follow(&self, velocity: V, duration: S) -> FollowResult<T, S> {
+        let res = self.motion().follow(velocity, duration);
+        match self.gear() {
+            Gear::Forward => res,
+            Gear::Backward => {
+                let pose = Pose::new(
+                    res.pose.position().clone(),
+                    res.pose.orientation().reversed(),
+                );
+                FollowResult::new(pose, res.complete)
+            }
+        }
+    }
+}
+
+impl<T, V, S> Follow<T, V, S> for ReedsShepp<T>
+where
+    T: Number,
+    V: Number,
+    S: Number,
+    T: Div<V, Output = S>,
+    V: Mul<S, Output = T>,
+    //
+    T: Neg<Output = T>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: Div<T>,
+    <T as Div<T>>::Output: HasValue<Output = <T as HasValue>::Output>,
+    //
+    <T as HasValue>::Output: AngleOps,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+{
+    fn follow(&self, velocity: V, duration: S) -> FollowResult<T, S> {
+        let mut total = S::ZERO;
+        let mut t = duration;
+        for element in self.elements().iter() {
+            let res = element.follow(velocity, t);
+            match res.complete {
+                Some(d) => {
+                    total += d;
+                    t -= d;
+                }
+                None => return res,
+            }
+        }
+        FollowResult::new(self.finish().clone(), Some(total))
+    }
+}
@@ -0,0 +1,309 @@
+//! GeoJSON import and export, with the `geojson` feature.
+//!
+//! Coordinates are the values of the numbers (`x.value()`), read and written as the first two
+//! numbers of GeoJSON positions.
+
+use super::*;
+use serde_json::{json, Value as Json};
+use std::f64::consts::PI;
+use std::fmt::Display;
+use std::ops::Mul;
+
+use sity::*;
+
+/// An error while reading or writing GeoJSON.
+#[derive(Debug)]
+pub enum GeoJsonError {
+    /// The text is not JSON.
+    Json(serde_json::Error),
+    /// The JSON is not the expected GeoJSON.
+    Format(String),
+    /// A ring does not form a valid polygon.
+    Polygon(PolygonError),
+    /// The tolerance of the chords replacing the arcs is not positive.
+    InvalidTolerance,
+}
+
+impl Display for GeoJsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeoJsonError::Json(e) => write!(f, "invalid JSON: {}", e),
+            GeoJsonError::Format(message) => write!(f, "invalid GeoJSON: {}", message),
+            GeoJsonError::Polygon(e) => write!(f, "invalid polygon: {}", e),
+            GeoJsonError::InvalidTolerance => {
+                write!(f, "the tolerance of the chords must be positive")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeoJsonError {}
+
+impl From<serde_json::Error> for GeoJsonError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
+
+impl From<PolygonError> for GeoJsonError {
+    fn from(value: PolygonError) -> Self {
+        Self::Polygon(value)
+    }
+}
+
+//-------------------------------------------------- Read --------------------------------------------------
+
+impl<T> MultiPolygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    /// Reads every `Polygon` and `MultiPolygon` geometry of a GeoJSON text: a feature collection,
+    /// a feature, a geometry collection or a bare geometry. Other geometries are skipped.
+    ///
+    /// The first ring of a GeoJSON polygon is its outer boundary and the others are its holes.
+    /// Rings may be given in any orientation.
+    pub fn from_geojson(text: &str) -> Result<Self, GeoJsonError> {
+        let json: Json = serde_json::from_str(text)?;
+        let mut polygons = vec![];
+        read_object(&json, &mut polygons)?;
+        Ok(MultiPolygon::new(polygons))
+    }
+}
+
+fn field<'a>(json: &'a Json, name: &str) -> Result<&'a Json, GeoJsonError> {
+    json.get(name)
+        .ok_or_else(|| GeoJsonError::Format(format!("missing `{}`", name)))
+}
+
+fn array<'a>(json: &'a Json, name: &str) -> Result<&'a Vec<Json>, GeoJsonError> {
+    json.as_array()
+        .ok_or_else(|| GeoJsonError::Format(format!("`{}` is not an array", name)))
+}
+
+fn read_object<T>(json: &Json, polygons: &mut Vec<PolygonWithHoles<T>>) -> Result<(), GeoJsonError>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    let kind = field(json, "type")?
+        .as_str()
+        .ok_or_else(|| GeoJsonError::Format("`type` is not a string".to_string()))?;
+    match kind {
+        "FeatureCollection" => {
+            for feature in array(field(json, "features")?, "features")? {
+                read_object(feature, polygons)?;
+            }
+        }
+        "Feature" => {
+            let geometry = field(json, "geometry")?;
+            if !geometry.is_null() {
+                read_object(geometry, polygons)?;
+            }
+        }
+        "GeometryCollection" => {
+            for geometry in array(field(json, "geometries")?, "geometries")? {
+                read_object(geometry, polygons)?;
+            }
+        }
+        "Polygon" => polygons.push(read_polygon(field(json, "coordinates")?)?),
+        "MultiPolygon" => {
+            for polygon in array(field(json, "coordinates")?, "coordinates")? {
+                polygons.push(read_polygon(polygon)?);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn read_polygon<T>(json: &Json) -> Result<PolygonWithHoles<T>, GeoJsonError>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    let mut rings = array(json, "coordinates")?
+        .iter()
+        .map(read_ring)
+        .collect::<Result<Vec<_>, _>>()?;
+    if rings.is_empty() {
+        return Err(GeoJsonError::Format("polygon without rings".to_string()));
+    }
+    let outer = rings.remove(0);
+    Ok(PolygonWithHoles::new(outer, rings))
+}
+
+/// A linear ring: its last position repeats the first one.
+fn read_ring<T>(json: &Json) -> Result<Polygon<T>, GeoJsonError>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    let mut points = array(json, "coordinates")?
+        .iter()
+        .map(|position| {
+            let coordinates = array(position, "position")?
+                .iter()
+                .take(2)
+                .map(|c| c.as_f64())
+                .collect::<Option<Vec<_>>>()
+                .filter(|c| c.len() == 2)
+                .ok_or_else(|| GeoJsonError::Format("invalid position".to_string()))?;
+            Ok(Point::new(
+                from_value(coordinates[0]),
+                from_value(coordinates[1]),
+            ))
+        })
+        .collect::<Result<Vec<_>, GeoJsonError>>()?;
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    Ok(Polygon::new(points)?)
+}
+
+fn from_value<T>(v: f64) -> T
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    T::ONE * (v / T::ONE.value())
+}
+
+//-------------------------------------------------- Write --------------------------------------------------
+
+fn position<T>(point: &Point<T>) -> Json
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    json!([point.x.value(), point.y.value()])
+}
+
+impl<T> Path<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Writes the path as a GeoJSON feature with a `LineString` geometry. Arcs are replaced by
+    /// chords that stay within `tolerance` of them, which must be positive.
+    pub fn to_geojson(&self, tolerance: T) -> Result<String, GeoJsonError> {
+        if tolerance.value().is_nan() || tolerance <= T::ZERO {
+            return Err(GeoJsonError::InvalidTolerance);
+        }
+        let mut points: Vec<Point<T>> = vec![];
+        let mut push = |p: Point<T>| {
+            if points.last() != Some(&p) {
+                points.push(p);
+            }
+        };
+        for traj in self.trajectories() {
+            chords(traj.start_rotation(), tolerance)
+                .into_iter()
+                .for_each(&mut push);
+            match traj.transition() {
+                Transition::Segment(seg) => {
+                    push(seg.first().clone());
+                    push(seg.second().clone());
+                }
+                Transition::Arc(arc) => chords(arc, tolerance).into_iter().for_each(&mut push),
+            }
+            chords(traj.finish_rotation(), tolerance)
+                .into_iter()
+                .for_each(&mut push);
+        }
+        Ok(json!({
+            "type": "Feature",
+            "geometry": {
+                "type": "LineString",
+                "coordinates": points.iter().map(position).collect::<Vec<_>>(),
+            },
+            "properties": {},
+        })
+        .to_string())
+    }
+}
+
+/// The points of the arc, from its start to its finish, so that each chord stays within
+/// `tolerance` of the arc.
+fn chords<T>(arc: &DirectedArc<T>, tolerance: T) -> Vec<Point<T>>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    let (c, r) = (arc.center(), arc.radius().value());
+    let sweep = match arc.direction() {
+        Direction::CounterClockWise => arc.delta_angle().value().abs(),
+        Direction::ClockWise => -arc.delta_angle().value().abs(),
+    };
+    // A chord of angle `step` is `r (1 - cos(step / 2))` away from its arc
+    let step = if tolerance.value() >= r {
+        PI
+    } else {
+        2.0 * (1.0 - tolerance.value() / r).acos()
+    };
+    let n = ((sweep.abs() / step).ceil() as usize).max(1);
+    let start = arc.start_angle().value();
+    let mut points = (1..n)
+        .map(|k| {
+            let angle = start + sweep * k as f64 / n as f64;
+            Point::new(
+                c.x + arc.radius() * angle.cos(),
+                c.y + arc.radius() * angle.sin(),
+            )
+        })
+        .collect::<Vec<_>>();
+    points.insert(0, arc.start_point());
+    points.push(arc.finish_point());
+    points
+}
+
+impl<T> Pose<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    /// Writes the poses as a GeoJSON feature collection of `Point` features. Their properties
+    /// give the heading, in radians counter-clockwise from the x axis, and the bearing, in
+    /// degrees clockwise from the y axis (north).
+    pub fn to_geojson(poses: &[Pose<T>]) -> String {
+        let features = poses
+            .iter()
+            .map(|pose| {
+                let o = pose.orientation();
+                let heading = o.dy.value().atan2(o.dx.value());
+                let bearing = (90.0 - heading.to_degrees()).rem_euclid(360.0);
+                json!({
+                    "type": "Feature",
+                    "geometry": {
+                        "type": "Point",
+                        "coordinates": position(pose.position()),
+                    },
+                    "properties": {
+                        "heading": heading,
+                        "bearing": bearing,
+                    },
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({
+            "type": "FeatureCollection",
+            "features": features,
+        })
+        .to_string()
+    }
+}
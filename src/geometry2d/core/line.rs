@@ -69,6 +69,23 @@ where
     }
 }
 
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for Line<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self {
+            point: transform.apply_to_point(&self.point),
+            vector: transform.apply_to_vector(&self.vector),
+        }
+    }
+}
+
 //-------------------------------------------------- To Vector --------------------------------------------------
 
 // impl<T> ToVector<T> for Line<T>
@@ -499,24 +516,58 @@ where
     <T as Pow2>::Output: Div,
     <<T as Pow2>::Output as Div>::Output: Number,
 {
+    /// Returns the intersection points, sorted along the line.
     pub fn intersection_to_polygon(&self, other: &Polygon<T>) -> Vec<Point<T>> {
-        let points = other
-            .segments()
-            .iter()
+        self.intersection_to_segments(other.segments().iter())
+    }
+
+    /// Returns the intersection points with the outer boundary and the holes, sorted along the line.
+    pub fn intersection_to_polygon_with_holes(&self, other: &PolygonWithHoles<T>) -> Vec<Point<T>> {
+        self.intersection_to_segments(other.segments().iter())
+    }
+
+    fn intersection_to_segments<'a, I>(&self, segments: I) -> Vec<Point<T>>
+    where
+        I: Iterator<Item = &'a Segment<T>>,
+        T: 'a,
+    {
+        let points = segments
             .filter_map(|seg| self.intersection_to_segment(seg))
             .collect::<Vec<_>>();
 
         // unicity
-        let mut v = vec![];
+        let mut v: Vec<Point<T>> = vec![];
         for pt in points.iter() {
             if !v.contains(pt) {
                 v.push(pt.clone());
             }
         }
+
+        // order along the line
+        v.sort_by(|a, b| {
+            let ta = Vector::from((&self.point, a)).dot_product(&self.vector);
+            let tb = Vector::from((&self.point, b)).dot_product(&self.vector);
+            ta.partial_cmp(&tb).unwrap_or(std::cmp::Ordering::Equal)
+        });
         v
     }
 }
 
+impl<T> Line<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    pub fn intersection_to_circle(&self, other: &Circle<T>) -> CircleIntersection<T> {
+        other.intersection_to_line(self)
+    }
+
+    pub fn intersection_to_arc(&self, other: &DirectedArc<T>) -> CircleIntersection<T> {
+        other.intersection_to_line(self)
+    }
+}
+
 //-------------------------------------------------- Display --------------------------------------------------
 
 impl<T: Number + Display> Display for Line<T> {
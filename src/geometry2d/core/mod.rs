@@ -1,9 +1,15 @@
 pub mod orientation;
 pub use orientation::*;
 
+pub mod predicates;
+pub use predicates::*;
+
 pub mod rotation;
 pub use rotation::*;
 
+pub mod transform;
+pub use transform::*;
+
 pub mod point;
 pub use point::*;
 
@@ -19,6 +25,26 @@ pub use segment::*;
 pub mod polygon;
 pub use polygon::*;
 
+pub mod polygon_with_holes;
+pub use polygon_with_holes::*;
+
+pub mod multi_polygon;
+pub use multi_polygon::*;
+
+pub mod boolean;
+pub use boolean::*;
+
+pub mod offset;
+pub use offset::*;
+
+pub mod boustrophedon;
+
+pub mod sweep;
+pub use sweep::*;
+
+pub mod tour;
+pub use tour::*;
+
 pub mod circle;
 pub use circle::*;
 
@@ -37,14 +63,104 @@ pub use trajectory::*;
 pub mod path;
 pub use path::*;
 
+pub mod reeds_shepp;
+pub use reeds_shepp::*;
+
+pub mod obstacle;
+pub use obstacle::*;
+
+pub mod collision;
+pub use collision::*;
+
+pub mod bounds;
+pub use bounds::*;
+
+pub mod spatial_index;
+pub use spatial_index::*;
+
+pub mod planner;
+pub use planner::*;
+
 pub mod follow;
 pub use follow::*;
 
 pub mod plot;
-// pub use plot::*;
+pub use plot::*;
+
+pub mod scene;
+pub use scene::*;
+
+#[cfg(feature = "serde")]
+pub mod serialize;
+
+#[cfg(feature = "geojson")]
+pub mod geojson;
+#[cfg(feature = "geojson")]
+pub use geojson::*;
+
+pub mod wkt;
+pub use wkt::*;
 
 use sity::*;
 
+//------------------------- Unit -------------------------
+
+/// A number type whose unit is known from the type itself. It is implemented for plain numbers
+/// and lengths in metres only.
+pub trait UnitName {
+    /// The symbol of the unit: `"m"` for metres, `"km"` for kilometres, empty for plain numbers.
+    fn unit_name() -> String;
+}
+
+impl UnitName for f64 {
+    fn unit_name() -> String {
+        String::new()
+    }
+}
+
+impl<T, P> UnitName for Metre_<T, P>
+where
+    Self: Number,
+    Self: HasValue<Output = f64>,
+{
+    /// The prefix `P` is read from the length of one unit, in metres.
+    fn unit_name() -> String {
+        let scale = Self::ONE.value();
+        let exponent = scale.log10().round() as i32;
+        match PREFIXES.iter().find(|(e, _)| *e == exponent) {
+            Some((e, prefix)) if (scale - 10f64.powi(*e)).abs() <= 1e-9 * scale => {
+                format!("{}m", prefix)
+            }
+            _ => format!("{} m", scale),
+        }
+    }
+}
+
+/// The SI prefixes, with their power of ten.
+const PREFIXES: [(i32, &str); 21] = [
+    (-24, "y"),
+    (-21, "z"),
+    (-18, "a"),
+    (-15, "f"),
+    (-12, "p"),
+    (-9, "n"),
+    (-6, "µ"),
+    (-3, "m"),
+    (-2, "c"),
+    (-1, "d"),
+    (0, ""),
+    (1, "da"),
+    (2, "h"),
+    (3, "k"),
+    (6, "M"),
+    (9, "G"),
+    (12, "T"),
+    (15, "P"),
+    (18, "E"),
+    (21, "Z"),
+    (24, "Y"),
+];
+
 //------------------------- ToVector -------------------------
 
 // pub trait ToVector<T>
@@ -74,3 +190,41 @@ pub trait IsParallel<O> {
 pub trait Contains<O> {
     fn contains(&self, other: &O) -> bool;
 }
+
+//------------------------- Mapping -------------------------
+
+/// A surface that can be covered with parallel passes.
+pub trait Mapping<T>
+where
+    T: Number,
+{
+    /// Returns the passes along `direction`, `distance` apart, that stay inside the surface.
+    fn mapping(&self, direction: &Vector<T>, distance: T) -> Vec<Segment<T>>;
+
+    /// Returns the passes grouped in cells, each driven back and forth before the next one, that
+    /// keep `clearance` away from the exclusion zones of the surface. By default, all the passes
+    /// form a single cell.
+    fn mapping_cells(
+        &self,
+        direction: &Vector<T>,
+        distance: T,
+        _clearance: T,
+    ) -> Vec<Vec<Segment<T>>> {
+        vec![self.mapping(direction, distance)]
+    }
+
+    /// Returns the zones that neither the passes nor the turns between them may enter, e.g. the
+    /// holes of the surface. None by default.
+    fn mapping_zones(&self) -> Vec<Polygon<T>> {
+        vec![]
+    }
+
+    /// Returns the candidate directions for the passes.
+    fn mapping_directions(&self) -> Vec<Vector<T>>;
+
+    /// Returns the vertices of the outer boundary, which bound the extent of the passes. None by
+    /// default, in which case the minimum width of the surface is unknown.
+    fn mapping_vertices(&self) -> Vec<Point<T>> {
+        vec![]
+    }
+}
@@ -0,0 +1,169 @@
+use std::ops::*;
+
+use super::*;
+use sity::*;
+
+/// A set of disjoint polygons with holes, as returned by the boolean operations.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MultiPolygon<T: Number> {
+    polygons: Vec<PolygonWithHoles<T>>,
+}
+
+//-------------------------------------------------- New --------------------------------------------------
+
+impl<T: Number> MultiPolygon<T> {
+    pub fn new(polygons: Vec<PolygonWithHoles<T>>) -> Self {
+        Self { polygons }
+    }
+
+    pub fn polygons(&self) -> &Vec<PolygonWithHoles<T>> {
+        &self.polygons
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.polygons.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.polygons.len()
+    }
+}
+
+impl<T> From<Polygon<T>> for MultiPolygon<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    fn from(value: Polygon<T>) -> Self {
+        Self::new(vec![value.into()])
+    }
+}
+
+impl<T: Number> From<PolygonWithHoles<T>> for MultiPolygon<T> {
+    fn from(value: PolygonWithHoles<T>) -> Self {
+        Self::new(vec![value])
+    }
+}
+
+impl<T> MultiPolygon<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+    <T as Mul>::Output: Mul<f64, Output = <T as Mul>::Output>,
+{
+    /// Returns the total area of the polygons.
+    pub fn area(&self) -> <T as Mul>::Output {
+        self.polygons
+            .iter()
+            .fold(<T as Mul>::Output::ZERO, |area, p| area + p.area())
+    }
+}
+
+//-------------------------------------------------- ToValue --------------------------------------------------
+
+impl<T> MultiPolygon<T>
+where
+    T: Number,
+{
+    pub fn to_value(&self) -> MultiPolygon<<T as HasValue>::Output> {
+        MultiPolygon::new(self.polygons.iter().map(|p| p.to_value()).collect())
+    }
+}
+
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for MultiPolygon<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self::new(
+            self.polygons
+                .iter()
+                .map(|p| p.transformed(transform))
+                .collect(),
+        )
+    }
+}
+
+//-------------------------------------------------- Contains --------------------------------------------------
+
+impl<T> Contains<Point<T>> for MultiPolygon<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    fn contains(&self, point: &Point<T>) -> bool {
+        self.polygons.iter().any(|p| p.contains(point))
+    }
+}
+
+//-------------------------------------------------- Mapping --------------------------------------------------
+
+impl<T> Mapping<T> for MultiPolygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    //
+    <T as HasValue>::Output: FromValue<usize>,
+    //
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul<T, Output = <T as Pow2>::Output>,
+    T: Mul<<<T as Pow2>::Output as Div>::Output, Output = T>,
+    //
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: DivAssign<<T as HasValue>::Output>,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Div,
+    <<T as Pow2>::Output as Div>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+{
+    /// Returns the passes of every polygon, one polygon after the other.
+    fn mapping(&self, direction: &Vector<T>, distance: T) -> Vec<Segment<T>> {
+        self.polygons
+            .iter()
+            .flat_map(|p| p.mapping(direction, distance))
+            .collect()
+    }
+
+    /// Returns the cells of every polygon, one polygon after the other.
+    fn mapping_cells(
+        &self,
+        direction: &Vector<T>,
+        distance: T,
+        clearance: T,
+    ) -> Vec<Vec<Segment<T>>> {
+        self.polygons
+            .iter()
+            .flat_map(|p| p.mapping_cells(direction, distance, clearance))
+            .collect()
+    }
+
+    fn mapping_zones(&self) -> Vec<Polygon<T>> {
+        self.polygons
+            .iter()
+            .flat_map(|p| p.mapping_zones())
+            .collect()
+    }
+
+    fn mapping_directions(&self) -> Vec<Vector<T>> {
+        self.polygons
+            .iter()
+            .flat_map(|p| p.mapping_directions())
+            .collect()
+    }
+    fn mapping_vertices(&self) -> Vec<Point<T>> {
+        self.polygons
+            .iter()
+            .flat_map(|p| p.mapping_vertices())
+            .collect()
+    }
+}
@@ -0,0 +1,77 @@
+use std::ops::*;
+
+use super::*;
+use sity::*;
+
+/// An area a vehicle must stay out of.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Obstacle<T: Number> {
+    Polygon(Polygon<T>),
+    Circle(Circle<T>),
+}
+
+//-------------------------------------------------- From/Into --------------------------------------------------
+
+impl<T: Number> From<Polygon<T>> for Obstacle<T> {
+    fn from(value: Polygon<T>) -> Self {
+        Self::Polygon(value)
+    }
+}
+
+impl<T: Number> From<Circle<T>> for Obstacle<T> {
+    fn from(value: Circle<T>) -> Self {
+        Self::Circle(value)
+    }
+}
+
+//-------------------------------------------------- ToValue --------------------------------------------------
+
+impl<T> Obstacle<T>
+where
+    T: Number,
+{
+    pub fn to_value(&self) -> Obstacle<<T as HasValue>::Output> {
+        match self {
+            Obstacle::Polygon(polygon) => Obstacle::Polygon(polygon.to_value()),
+            Obstacle::Circle(circle) => Obstacle::Circle(circle.to_value()),
+        }
+    }
+}
+
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for Obstacle<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        match self {
+            Obstacle::Polygon(polygon) => Obstacle::Polygon(polygon.transformed(transform)),
+            Obstacle::Circle(circle) => Obstacle::Circle(circle.transformed(transform)),
+        }
+    }
+}
+
+//-------------------------------------------------- Contains --------------------------------------------------
+
+impl<T> Contains<Point<T>> for Obstacle<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+    <T as Mul>::Output: Div<T, Output = T>,
+{
+    /// Points on the boundary are contained.
+    fn contains(&self, point: &Point<T>) -> bool {
+        match self {
+            Obstacle::Polygon(polygon) => polygon.contains(point),
+            Obstacle::Circle(circle) => circle.contains(point),
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use super::boolean::{assemble, link, rings_of, select, tolerance, union_rings};
+use super::*;
+use std::fmt::Display;
+use std::ops::Mul;
+
+use sity::*;
+
+/// How the corners are shaped when offsetting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Corner<T: Number> {
+    /// Circular arcs centered on the original vertices, approximated by a polygon lying outside
+    /// the arc and within `tolerance` of it.
+    Round { tolerance: T },
+    /// Sharp corners, bevelled when the mitre point is further than `limit` times the offset distance.
+    Mitre { limit: f64 },
+}
+
+impl<T> Corner<T>
+where
+    T: Number,
+{
+    pub fn to_value(&self) -> Corner<<T as HasValue>::Output> {
+        match *self {
+            Corner::Round { tolerance } => Corner::Round {
+                tolerance: tolerance.value(),
+            },
+            Corner::Mitre { limit } => Corner::Mitre { limit },
+        }
+    }
+}
+
+//-------------------------------------------------- Error --------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OffsetError {
+    /// The tolerance of [`Corner::Round`] is not positive.
+    InvalidTolerance,
+}
+
+impl Display for OffsetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OffsetError::InvalidTolerance => {
+                write!(f, "the tolerance of round corners must be positive")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OffsetError {}
+
+//-------------------------------------------------- Offset --------------------------------------------------
+
+impl<T> MultiPolygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Moves the boundaries by `distance`: outward (inflating) when positive, inward (shrinking)
+    /// when negative. Overlapping parts are merged and collapsed parts are removed, so the result
+    /// may have more or fewer polygons than `self`.
+    pub fn offset(&self, distance: T, corner: Corner<T>) -> Result<Self, OffsetError> {
+        let corner = corner.to_value();
+        if let Corner::Round { tolerance } = corner {
+            if tolerance.is_nan() || tolerance <= 0.0 {
+                return Err(OffsetError::InvalidTolerance);
+            }
+        }
+        let d = distance.value();
+        let rings = rings_of(self);
+        if d == 0.0 || rings.is_empty() {
+            return Ok(self.clone());
+        }
+        let eps = tolerance(&rings, &[]);
+
+        // Band of width |d| on both sides of every boundary
+        let mut band = vec![];
+        for ring in rings.iter() {
+            band.extend(ring_band(ring, d.abs(), corner));
+        }
+
+        let boundaries = if d > 0.0 {
+            band.extend(rings);
+            union_rings(&band, eps)
+        } else {
+            let band = union_rings(&band, eps);
+            link(
+                &select(&rings, &band, BooleanOperation::Difference, eps),
+                eps,
+            )
+        };
+        Ok(assemble(boundaries, eps))
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    /// Offsets the polygon outward when `distance` is positive and inward when negative.
+    /// Shrinking a concave polygon may split it in several parts.
+    pub fn offset(&self, distance: T, corner: Corner<T>) -> Result<MultiPolygon<T>, OffsetError> {
+        MultiPolygon::from(self.clone()).offset(distance, corner)
+    }
+}
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Offsets the polygon outward when `distance` is positive and inward when negative:
+    /// the holes shrink when the outer boundary grows, and conversely.
+    pub fn offset(&self, distance: T, corner: Corner<T>) -> Result<MultiPolygon<T>, OffsetError> {
+        MultiPolygon::from(self.clone()).offset(distance, corner)
+    }
+}
+
+//-------------------------------------------------- Internals --------------------------------------------------
+
+/// Returns the counter-clockwise pieces covering the band of half-width `d` around the ring:
+/// one rectangle per edge, plus a corner piece filling the gap on the outer side of each turn.
+fn ring_band(ring: &Polygon<f64>, d: f64, corner: Corner<f64>) -> Vec<Polygon<f64>> {
+    let points = ring.points();
+    let n = points.len();
+    let mut pieces = vec![];
+    for i in 0..n {
+        let (a, b) = (&points[i], &points[(i + 1) % n]);
+        let v = Vector::from((a, b));
+        if v.norm() == 0.0 {
+            continue;
+        }
+        let normal = v.perpendicular_counterclockwise().scale(d);
+        pieces.push(Polygon::new_unchecked(vec![
+            a - &normal,
+            b - &normal,
+            b + &normal,
+            a + &normal,
+        ]));
+
+        let c = &points[(i + 2) % n];
+        if let Some(piece) = corner_piece(a, b, c, d, corner) {
+            pieces.push(piece.with_winding(Orientation::CounterClockWise));
+        }
+    }
+    pieces
+}
+
+/// Returns the piece filling the gap between the rectangles of edges `[a, b]` and `[b, c]`.
+fn corner_piece(
+    a: &Point<f64>,
+    b: &Point<f64>,
+    c: &Point<f64>,
+    d: f64,
+    corner: Corner<f64>,
+) -> Option<Polygon<f64>> {
+    let u1 = Vector::from((a, b));
+    let u2 = Vector::from((b, c));
+    if u1.norm() == 0.0 || u2.norm() == 0.0 {
+        return None;
+    }
+    let turn = u1.cross_product(&u2).atan2(u1.dot_product(&u2));
+    if turn.abs() <= f64::EPSILON {
+        return None;
+    }
+    // Unit normals on the outer side of the turn
+    let (n1, n2) = if turn > 0.0 {
+        (u1.perpendicular_clockwise(), u2.perpendicular_clockwise())
+    } else {
+        (
+            u1.perpendicular_counterclockwise(),
+            u2.perpendicular_counterclockwise(),
+        )
+    };
+    let (n1, n2) = (n1.normalized(), n2.normalized());
+    let p1 = b + &n1 * d;
+    let p2 = b + &n2 * d;
+
+    match corner {
+        Corner::Mitre { limit } => {
+            let k = 1.0 + n1.dot_product(&n2);
+            let mitre = if k > f64::EPSILON {
+                (2.0 / k).sqrt()
+            } else {
+                f64::INFINITY
+            };
+            if mitre <= limit {
+                let m = b + (&n1 + &n2) * (d / k);
+                Some(Polygon::new_unchecked(vec![b.clone(), p1, m, p2]))
+            } else {
+                Some(Polygon::new_unchecked(vec![b.clone(), p1, p2]))
+            }
+        }
+        Corner::Round { tolerance } => Some(round_corner(
+            b,
+            &n1,
+            turn.abs(),
+            turn.signum(),
+            d,
+            tolerance,
+        )),
+    }
+}
+
+/// Polygon around the arc of radius `d` centered on `b`, going from the direction `n1`
+/// by `angle` in the `sign` (positive counter-clockwise) direction, within `tolerance` of it.
+fn round_corner(
+    b: &Point<f64>,
+    n1: &Vector<f64>,
+    angle: f64,
+    sign: f64,
+    d: f64,
+    tolerance: f64,
+) -> Polygon<f64> {
+    // The sides tangent to the arc over an angle `step` end `d / cos(step / 2) - d` away from it
+    let step = 2.0 * (d / (d + tolerance)).acos();
+    let segments = (angle / step).ceil().max(1.0) as usize;
+    let step = angle / segments as f64;
+    let direction = if sign > 0.0 {
+        Direction::CounterClockWise
+    } else {
+        Direction::ClockWise
+    };
+    let start = Radian::new(n1.dy.atan2(n1.dx));
+
+    // Exact end points on the arc, and the sides tangent to the arc in between
+    let arc = DirectedArc::new(
+        DirectedCircle::new(Circle::new(b.clone(), d), direction),
+        start,
+        Radian::new(angle),
+    );
+    let outer = DirectedArc::new(
+        DirectedCircle::new(Circle::new(b.clone(), d / (step / 2.0).cos()), direction),
+        start + Radian::new(sign * step / 2.0),
+        Radian::new(step * (segments - 1) as f64),
+    );
+
+    let mut points = vec![b.clone(), arc.start_point()];
+    if segments == 1 {
+        // The sides tangent at both ends meet on the bisector
+        points.push(outer.start_point());
+    } else {
+        points.extend(outer.discretize(segments - 1));
+    }
+    points.push(arc.finish_point());
+    Polygon::new_unchecked(points)
+}
@@ -11,6 +11,8 @@ pub enum Orientation {
     CounterClockWise,
 }
 
+/// Returns the orientation of the turn `p0`, `p1`, `p2`, comparing the cross product exactly with
+/// zero. See [`Predicates`] for robust variants.
 pub fn points_orientation<T>(p0: &Point<T>, p1: &Point<T>, p2: &Point<T>) -> Orientation
 where
     T: Number,
@@ -27,21 +29,6 @@ where
     if x < <T as std::ops::Mul>::Output::ZERO {
         return Orientation::CounterClockWise;
     }
-    panic!("undefined orientation")
+    // NaN coordinates
+    Orientation::Collinear
 }
-
-// pub fn is_convex<T: Float>(polygon: &Vec<Point2D<T>>) -> bool {
-//     assert!(polygon.len() >= 3);
-
-//     let orientation = points_orientation(&polygon[0], &polygon[1], &polygon[2]);
-//     for index in 1..=polygon.len() {
-//         let p0 = &polygon[index];
-//         let p1 = &polygon[(index + 1) % polygon.len()];
-//         let p2 = &polygon[(index + 2) % polygon.len()];
-//         let o = points_orientation(p0, p1, p2);
-//         if o != orientation {
-//             return false;
-//         }
-//     }
-//     true
-// }
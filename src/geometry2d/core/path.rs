@@ -17,6 +17,108 @@ where
     trajectories: Vec<Trajectory<T>>,
 }
 
+/// The order in which the parallel passes of a mapping are driven.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PassOrder {
+    /// Each pass after its neighbour.
+    #[default]
+    Sequential,
+    /// Every `n`-th pass, round after round until all passes are driven: the turns between
+    /// passes are wider, which avoids loops when the spacing is smaller than the turning diameter.
+    /// Each round starts from the end that keeps it away from the last pass, so that two
+    /// consecutive passes are never neighbours when the number of passes allows it.
+    Skip(usize),
+    /// The order giving the shortest path, among the sequential one and the useful skips.
+    Shortest,
+}
+
+/// Most steps of the search for an order of the rounds of [`PassOrder::Skip`]. The search only
+/// runs that long with many rounds and no suitable order: the rounds are then driven back and forth.
+const CHAIN_STEPS: usize = 10_000;
+
+impl PassOrder {
+    /// Reorders `passes` for the fixed orders. `Shortest` keeps them in order: it depends on the
+    /// turning radius, and [`Path::mapping_with_order`] resolves it by comparing the other orders.
+    pub(super) fn apply<S: Clone>(&self, passes: &[S]) -> Vec<S> {
+        match *self {
+            PassOrder::Skip(n) if n > 1 && !passes.is_empty() => {
+                let rounds = (0..n.min(passes.len()))
+                    .map(|round| (round..passes.len()).step_by(n).collect::<Vec<_>>())
+                    .collect::<Vec<_>>();
+                let mut used = vec![false; rounds.len()];
+                used[0] = true;
+                let mut order = rounds[0].clone();
+                let mut steps = CHAIN_STEPS;
+                if !Self::chain_rounds(&rounds, &mut used, &mut order, passes.len(), &mut steps) {
+                    // Too few passes, or too many rounds: back and forth, round after round
+                    order = rounds
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(i, round)| {
+                            let mut round = round.clone();
+                            if i % 2 == 1 {
+                                round.reverse();
+                            }
+                            round
+                        })
+                        .collect();
+                }
+                order.into_iter().map(|i| passes[i].clone()).collect()
+            }
+            _ => passes.to_vec(),
+        }
+    }
+
+    /// Appends the unused rounds to `order`, each one in either direction, so that no pass
+    /// follows one of its neighbours. The closest starts are tried first. Returns `false` when
+    /// there is no such order, or when none is found within `steps`.
+    fn chain_rounds(
+        rounds: &[Vec<usize>],
+        used: &mut [bool],
+        order: &mut Vec<usize>,
+        count: usize,
+        steps: &mut usize,
+    ) -> bool {
+        if order.len() == count {
+            return true;
+        }
+        if *steps == 0 {
+            return false;
+        }
+        *steps -= 1;
+        let last = *order.last().unwrap();
+        let mut starts = vec![];
+        for (i, round) in rounds.iter().enumerate().filter(|(i, _)| !used[*i]) {
+            for reverse in [false, true] {
+                let first = if reverse {
+                    round[round.len() - 1]
+                } else {
+                    round[0]
+                };
+                if first.abs_diff(last) >= 2 && !(reverse && round.len() == 1) {
+                    starts.push((first.abs_diff(last), i, reverse));
+                }
+            }
+        }
+        starts.sort();
+        for (_, i, reverse) in starts {
+            let length = order.len();
+            used[i] = true;
+            if reverse {
+                order.extend(rounds[i].iter().rev());
+            } else {
+                order.extend(rounds[i].iter());
+            }
+            if Self::chain_rounds(rounds, used, order, count, steps) {
+                return true;
+            }
+            order.truncate(length);
+            used[i] = false;
+        }
+        false
+    }
+}
+
 //-------------------------------------------------- New --------------------------------------------------
 
 impl<T> Path<T>
@@ -51,6 +153,26 @@ where
     }
 }
 
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for Path<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self {
+            trajectories: self
+                .trajectories
+                .iter()
+                .map(|traj| traj.transformed(transform))
+                .collect(),
+        }
+    }
+}
+
 //-------------------------------------------------- Length --------------------------------------------------
 
 impl<T> Path<T>
@@ -125,6 +247,7 @@ where
 impl<T> Path<T>
 where
     T: Number + AngleFactory,
+    T: HasValue<Output = f64>,
     //
     <T as HasValue>::Output: AngleOps,
     <T as HasValue>::Output: FromValue<usize>,
@@ -156,36 +279,161 @@ where
     T: Pow4,
     <T as Pow4>::Output: Root2<Output = <T as Pow2>::Output>,
 {
-    pub fn mapping(
+    pub fn mapping<P: Mapping<T>>(
         start: &Pose<T>,
         direction: &Vector<T>,
         radius: T,
         distance: T,
-        polygon: &Polygon<T>,
+        polygon: &P,
     ) -> Option<Self> {
-        let mut segments = polygon.mapping(direction, distance);
-        if segments.is_empty() {
+        Self::mapping_with_order(
+            start,
+            direction,
+            radius,
+            distance,
+            polygon,
+            PassOrder::Sequential,
+        )
+    }
+
+    /// Covers `polygon` like [`Path::mapping`], driving the passes in the given `order`.
+    ///
+    /// When the polygon splits its passes in several cells, e.g. around holes, each cell is
+    /// covered before the one with the closest pass. The cells keep a turning diameter away from
+    /// the [`Mapping::mapping_zones`], and the turns and the moves between cells avoid them.
+    ///
+    /// Returns `None` when the polygon gives no pass, or when a turn cannot avoid the zones even
+    /// with a detour planned by [`Path::plan`].
+    pub fn mapping_with_order<P: Mapping<T>>(
+        start: &Pose<T>,
+        direction: &Vector<T>,
+        radius: T,
+        distance: T,
+        polygon: &P,
+        order: PassOrder,
+    ) -> Option<Self> {
+        let mut cells = polygon.mapping_cells(direction, distance, radius + radius);
+        cells.retain(|segments| !segments.is_empty());
+        if cells.is_empty() {
             return None;
         }
-        // Find first
-        if start.position().distance(segments.first().unwrap())
-            > start.position().distance(segments.last().unwrap())
-        {
+
+        let mut poses = vec![start.clone()];
+        while !cells.is_empty() {
+            let last = poses.last().unwrap().clone();
+            let segments = Self::closest_cell(&mut cells, last.position());
+            let segments = match order {
+                PassOrder::Shortest => Self::shortest_order(&last, radius, distance, &segments),
+                _ => order.apply(&segments),
+            };
+            Self::push_passes(&mut poses, &segments);
+        }
+        let zones = polygon
+            .mapping_zones()
+            .into_iter()
+            .map(Obstacle::from)
+            .collect::<Vec<_>>();
+        Self::create_clear(radius, &poses, &zones).ok()
+    }
+
+    /// Joins the poses like [`Path::create`], with the shortest trajectory that stays out of
+    /// `obstacles`, or a planned detour when none does. Fails with the error of the planner, or
+    /// with [`PlanError::NotFound`] when two poses cannot be joined at all.
+    fn create_clear(
+        radius: T,
+        poses: &[Pose<T>],
+        obstacles: &[Obstacle<T>],
+    ) -> Result<Self, PlanError> {
+        if obstacles.is_empty() {
+            return Self::create(radius, &poses.to_vec()).ok_or(PlanError::NotFound);
+        }
+        let mut trajectories = vec![];
+        for pts in poses.windows(2) {
+            let mut words = Trajectory::create_all(&pts[0], &pts[1], radius)
+                .into_iter()
+                .filter(|traj| traj.length().value().is_finite())
+                .collect::<Vec<_>>();
+            words.sort_by(|a, b| {
+                a.length()
+                    .partial_cmp(&b.length())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            match words
+                .into_iter()
+                .find(|traj| !obstacles.iter().any(|o| traj.collides(o)))
+            {
+                Some(traj) => trajectories.push(traj),
+                None => trajectories.extend(
+                    Self::plan(&pts[0], &pts[1], radius, obstacles, radius, 8)?.trajectories,
+                ),
+            }
+        }
+        Ok(Self { trajectories })
+    }
+
+    /// Removes the cell entered by the pass closest to `position`, its first or its last one,
+    /// and returns its passes starting from this one.
+    pub(crate) fn closest_cell(
+        cells: &mut Vec<Vec<Segment<T>>>,
+        position: &Point<T>,
+    ) -> Vec<Segment<T>> {
+        let mut best = (0, false, None);
+        for (i, segments) in cells.iter().enumerate() {
+            for (reverse, seg) in [(false, segments.first()), (true, segments.last())] {
+                let d = position.distance(seg.unwrap());
+                if best.2.is_none_or(|b| d < b) {
+                    best = (i, reverse, Some(d));
+                }
+            }
+        }
+        let mut segments = cells.remove(best.0);
+        if best.1 {
             segments.reverse();
         }
-        // Dermine segment polarity
-        // let mut rev = false;
-        // if start.position().distance(segments.first().unwrap().first())
-        //     > start
-        //         .position()
-        //         .distance(segments.first().unwrap().second())
-        // {
-        //     // segments = segments.iter().map(|s| s.reversed()).collect();
-        //     rev = true;
-        // }
+        segments
+    }
+
+    /// Returns the passes in the order giving the shortest path from `start`, among the
+    /// sequential one and the useful skips.
+    fn shortest_order(
+        start: &Pose<T>,
+        radius: T,
+        distance: T,
+        segments: &[Segment<T>],
+    ) -> Vec<Segment<T>> {
+        // Skipping more passes than needed to turn without a loop only makes the turns longer
+        let mut orders = vec![PassOrder::Sequential];
+        for k in 2..segments.len() {
+            orders.push(PassOrder::Skip(k));
+            if distance * <T as HasValue>::Output::from_value(k) >= radius + radius {
+                break;
+            }
+        }
+        let mut best = (None, segments.to_vec());
+        for order in orders {
+            let ordered = order.apply(segments);
+            if let Some(p) = Self::from_passes(start, radius, &ordered) {
+                let l = p.length();
+                if best.0.is_none_or(|b| l < b) {
+                    best = (Some(l), ordered);
+                }
+            }
+        }
+        best.1
+    }
+
+    fn from_passes(start: &Pose<T>, radius: T, segments: &[Segment<T>]) -> Option<Self> {
         // Generate Pose
-        let mut last_point = start.position().clone();
         let mut poses = vec![start.clone()];
+        Self::push_passes(&mut poses, segments);
+        // Find complete trajectory
+        Self::create(radius, &poses)
+    }
+
+    /// Appends the poses driving along each pass in turn, entering it by its end closest to the
+    /// last pose.
+    pub(crate) fn push_passes(poses: &mut Vec<Pose<T>>, segments: &[Segment<T>]) {
+        let mut last_point = poses.last().unwrap().position().clone();
         for segment in segments.iter() {
             let seg =
                 if last_point.distance(segment.first()) <= last_point.distance(segment.second()) {
@@ -202,20 +450,17 @@ where
             last_point = pose.position().clone();
             poses.push(pose);
         }
-        // Find complete trajectory
-        Self::create(radius, &poses)
     }
 
-    pub fn optimal_mapping(
+    pub fn optimal_mapping<P: Mapping<T>>(
         start: &Pose<T>,
         radius: T,
         distance: T,
-        polygon: &Polygon<T>,
+        polygon: &P,
     ) -> Option<Self> {
         let mut length = T::ZERO;
         let mut path = None;
-        for seg in polygon.segments() {
-            let direction = seg.to_vector();
+        for direction in polygon.mapping_directions() {
             match Self::mapping(start, &direction, radius, distance, polygon) {
                 Some(p) => {
                     let l = p.length();
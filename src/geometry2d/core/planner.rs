@@ -0,0 +1,328 @@
+use super::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::f64::consts::PI;
+use std::fmt::Display;
+use std::ops::{Div, DivAssign, Mul};
+
+use sity::*;
+
+/// The most poses a lattice may hold.
+pub const MAX_LATTICE_POSES: usize = 1_000_000;
+
+//-------------------------------------------------- Error --------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlanError {
+    /// The lattice spacing is not a positive finite length.
+    InvalidSpacing,
+    /// The lattice would hold this many poses, more than [`MAX_LATTICE_POSES`].
+    TooManyPoses(usize),
+    /// The start or the goal is inside an obstacle.
+    Blocked,
+    /// No path joins the start to the goal on the lattice.
+    NotFound,
+}
+
+impl Display for PlanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanError::InvalidSpacing => write!(f, "lattice spacing must be positive"),
+            PlanError::TooManyPoses(n) => write!(
+                f,
+                "lattice needs {} poses, more than the {} allowed",
+                n, MAX_LATTICE_POSES
+            ),
+            PlanError::Blocked => write!(f, "start or goal is inside an obstacle"),
+            PlanError::NotFound => write!(f, "no path found"),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+//-------------------------------------------------- Plan --------------------------------------------------
+
+impl<T> Path<T>
+where
+    T: Number + AngleFactory,
+    T: HasValue<Output = f64>,
+    //
+    <T as HasValue>::Output: AngleOps,
+    <T as HasValue>::Output: FromValue<usize>,
+    <T as HasValue>::Output: Mul<T, Output = T>,
+    <T as HasValue>::Output: Mul<<T as Pow2>::Output, Output = <T as Pow2>::Output>,
+    //
+    T: std::ops::Neg<Output = T>,
+    //
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul<T, Output = <T as Pow2>::Output>,
+    T: Mul<<<T as Pow2>::Output as Div>::Output, Output = T>,
+    T: Mul<<T as Pow2>::Output, Output = <T as Pow3>::Output>,
+    //
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: DivAssign<<T as HasValue>::Output>,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number + AngleFactory,
+    <T as Pow2>::Output: Div,
+    <T as Pow2>::Output: Root2<Output = T>,
+    <T as Pow2>::Output: HasValue<Output = <T as HasValue>::Output>,
+    <T as Pow2>::Output: Mul<T, Output = <T as Pow3>::Output>,
+    <<T as Pow2>::Output as Div>::Output: Number,
+    //
+    T: Pow3,
+    <T as Pow3>::Output: Div<<T as Pow2>::Output, Output = T>,
+    <T as Pow3>::Output: Mul<T, Output = <T as Pow4>::Output>,
+    //
+    T: Pow4,
+    <T as Pow4>::Output: Root2<Output = <T as Pow2>::Output>,
+{
+    /// Plans a path from `start` to `goal` that stays out of `obstacles`, turning no tighter
+    /// than `radius`.
+    ///
+    /// The search is an A* over a lattice of poses, `spacing` apart with `headings` orientations,
+    /// covering the start, the goal and the obstacles. Two poses closer than `2.5 * spacing` are
+    /// joined by the shortest word of [`Trajectory::create_all`] that hits no obstacle, and every
+    /// expanded pose also tries to reach the goal directly.
+    ///
+    /// Fails, in this order, when `spacing` is not positive, when the start or the goal is
+    /// blocked, when the lattice would exceed [`MAX_LATTICE_POSES`], or when no path is found.
+    pub fn plan(
+        start: &Pose<T>,
+        goal: &Pose<T>,
+        radius: T,
+        obstacles: &[Obstacle<T>],
+        spacing: T,
+        headings: usize,
+    ) -> Result<Self, PlanError> {
+        let free = |traj: &Trajectory<T>| !obstacles.iter().any(|o| traj.collides(o));
+        let steer = |from: &Pose<T>, to: &Pose<T>| {
+            let mut words = Trajectory::create_all(from, to, radius)
+                .into_iter()
+                .map(|traj| (traj.length().value(), traj))
+                .filter(|(l, _)| l.is_finite())
+                .collect::<Vec<_>>();
+            words.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+            words.into_iter().find(|(_, traj)| free(traj))
+        };
+        let step = spacing.value();
+        if !(step > 0.0 && step.is_finite()) {
+            return Err(PlanError::InvalidSpacing);
+        }
+
+        let obstacles = obstacles.iter().map(|o| o.to_value()).collect::<Vec<_>>();
+        if [start, goal]
+            .iter()
+            .any(|pose| blocked(&position(pose), &obstacles))
+        {
+            return Err(PlanError::Blocked);
+        }
+        let lattice = Lattice::new(start, goal, &obstacles, radius.value(), step, headings)?;
+        let nodes = &lattice.poses;
+        let reach = 2.5 * step;
+        let target = position(goal);
+        let heuristic = |i: usize| position(&nodes[i]).distance(&target);
+
+        // Node 0 is the start, node 1 the goal
+        let mut cost = vec![f64::INFINITY; nodes.len()];
+        let mut parent: Vec<Option<(usize, Trajectory<T>)>> = vec![None; nodes.len()];
+        let mut closed = vec![false; nodes.len()];
+        let mut open = BinaryHeap::new();
+        cost[0] = 0.0;
+        open.push(Open(heuristic(0), 0));
+        while let Some(Open(_, i)) = open.pop() {
+            if closed[i] {
+                continue;
+            }
+            if i == 1 {
+                break;
+            }
+            closed[i] = true;
+
+            let mut next = lattice.around(&position(&nodes[i]), reach);
+            next.push(1);
+            for j in next {
+                if closed[j] || j == i {
+                    continue;
+                }
+                if let Some((l, traj)) = steer(&nodes[i], &nodes[j]) {
+                    if cost[i] + l < cost[j] {
+                        cost[j] = cost[i] + l;
+                        parent[j] = Some((i, traj));
+                        open.push(Open(cost[j] + heuristic(j), j));
+                    }
+                }
+            }
+        }
+
+        let mut trajectories = vec![];
+        let mut i = 1;
+        while let Some((previous, traj)) = parent[i].take() {
+            trajectories.push(traj);
+            i = previous;
+        }
+        if i != 0 || trajectories.is_empty() {
+            return Err(PlanError::NotFound);
+        }
+        trajectories.reverse();
+        Ok(Path::new(trajectories))
+    }
+}
+
+//-------------------------------------------------- Internals --------------------------------------------------
+
+/// Entry of the open set, ordered by increasing estimated cost.
+struct Open(f64, usize);
+
+impl PartialEq for Open {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Open {}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// The poses of the search, bucketed by lattice cell.
+struct Lattice<T>
+where
+    T: Number,
+{
+    poses: Vec<Pose<T>>,
+    step: f64,
+    origin: Point<f64>,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+}
+
+impl<T> Lattice<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// The start, the goal, then the free lattice poses of the box around everything, with a
+    /// margin of one turning diameter. Fails when they would be more than [`MAX_LATTICE_POSES`].
+    fn new(
+        start: &Pose<T>,
+        goal: &Pose<T>,
+        obstacles: &[Obstacle<f64>],
+        radius: f64,
+        step: f64,
+        headings: usize,
+    ) -> Result<Self, PlanError> {
+        let mut points = vec![position(start), position(goal)];
+        for obstacle in obstacles {
+            match obstacle {
+                Obstacle::Polygon(polygon) => points.extend(polygon.points().iter().cloned()),
+                Obstacle::Circle(circle) => {
+                    let (c, r) = (circle.center(), circle.radius());
+                    points.extend([c.translated(-r, -r), c.translated(r, r)]);
+                }
+            }
+        }
+        let margin = 2.0 * radius + step;
+        let min = points
+            .iter()
+            .fold(Point::new(f64::INFINITY, f64::INFINITY), |m, p| {
+                Point::new(m.x.min(p.x), m.y.min(p.y))
+            });
+        let max = points
+            .iter()
+            .fold(Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY), |m, p| {
+                Point::new(m.x.max(p.x), m.y.max(p.y))
+            });
+        let origin = min.translated(-margin, -margin);
+        let size = [
+            ((max.x - min.x + 2.0 * margin) / step).ceil(),
+            ((max.y - min.y + 2.0 * margin) / step).ceil(),
+        ];
+        let headings = headings.max(1);
+        let count = 2.0 + (size[0] + 1.0) * (size[1] + 1.0) * headings as f64;
+        if count > MAX_LATTICE_POSES as f64 {
+            return Err(PlanError::TooManyPoses(
+                count.min(usize::MAX as f64) as usize
+            ));
+        }
+        let size = size.map(|n| n as i64);
+
+        let mut lattice = Self {
+            poses: vec![],
+            step,
+            origin: origin.clone(),
+            cells: HashMap::new(),
+        };
+        lattice.push(start.clone());
+        lattice.push(goal.clone());
+        for i in 0..=size[0] {
+            for j in 0..=size[1] {
+                let p = origin.translated(i as f64 * step, j as f64 * step);
+                if blocked(&p, obstacles) {
+                    continue;
+                }
+                for k in 0..headings {
+                    let angle = k as f64 * 2.0 * PI / headings as f64;
+                    lattice.push(Pose::new(
+                        Point::new(T::ONE * p.x, T::ONE * p.y),
+                        Vector::new(T::ONE * angle.cos(), T::ONE * angle.sin()),
+                    ));
+                }
+            }
+        }
+        Ok(lattice)
+    }
+
+    fn cell(&self, p: &Point<f64>) -> (i64, i64) {
+        (
+            ((p.x - self.origin.x) / self.step).floor() as i64,
+            ((p.y - self.origin.y) / self.step).floor() as i64,
+        )
+    }
+
+    fn push(&mut self, pose: Pose<T>) {
+        let cell = self.cell(&position(&pose));
+        self.cells.entry(cell).or_default().push(self.poses.len());
+        self.poses.push(pose);
+    }
+
+    /// Returns the poses closer than `reach` to `p`.
+    fn around(&self, p: &Point<f64>, reach: f64) -> Vec<usize> {
+        let (ci, cj) = self.cell(p);
+        let n = (reach / self.step).ceil() as i64;
+        let mut res = vec![];
+        for i in ci - n..=ci + n {
+            for j in cj - n..=cj + n {
+                for &k in self.cells.get(&(i, j)).into_iter().flatten() {
+                    if position(&self.poses[k]).distance(p) <= reach {
+                        res.push(k);
+                    }
+                }
+            }
+        }
+        res
+    }
+}
+
+fn position<T>(pose: &Pose<T>) -> Point<f64>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    pose.position().to_value()
+}
+
+/// Whether `p` is inside (or on the boundary of) an obstacle.
+fn blocked(p: &Point<f64>, obstacles: &[Obstacle<f64>]) -> bool {
+    obstacles.iter().any(|o| o.contains(p))
+}
@@ -1,10 +1,16 @@
 use super::{
-    Circle as GCircle, DirectedArc, Direction, Path, Point, Polygon, Pose, Segment, Trajectory,
+    Circle as GCircle, DirectedArc, Direction, Gear, MultiPolygon, Path, Point, Polygon,
+    PolygonWithHoles, Pose, ReedsShepp, Segment, Trajectory, Transition,
 };
 use sity::*;
 
+use plotters::coord::types::RangedCoordf64;
 use plotters::prelude::*;
 
+/// A chart in the coordinates of the shapes, on any plotters backend: a `BitMapBackend` for PNG
+/// files or in-memory buffers, an `SVGBackend` for SVG files or strings...
+pub type Chart<'a, DB> = ChartContext<'a, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>;
+
 //-------------------------------------------------- Point --------------------------------------------------
 
 impl<T> Point<T>
@@ -12,19 +18,7 @@ where
     T: Number,
     T: HasValue<Output = f64>,
 {
-    pub fn draw(
-        &self,
-        chart: &mut ChartContext<
-            '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        >,
-        color: RGBColor,
-        size: i32,
-    ) {
+    pub fn draw<DB: DrawingBackend>(&self, chart: &mut Chart<'_, DB>, color: RGBColor, size: i32) {
         let point = self.to_value();
         let x = point.x;
         let y = point.y;
@@ -46,16 +40,9 @@ where
     T: Number,
     T: HasValue<Output = f64>,
 {
-    pub fn draw<S: Into<ShapeStyle>>(
+    pub fn draw<DB: DrawingBackend, S: Into<ShapeStyle>>(
         &self,
-        chart: &mut ChartContext<
-            '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        >,
+        chart: &mut Chart<'_, DB>,
         style: S,
     ) {
         let circle = self.to_value();
@@ -82,16 +69,9 @@ where
     T: Number,
     T: HasValue<Output = f64>,
 {
-    pub fn draw<S: Into<ShapeStyle>>(
+    pub fn draw<DB: DrawingBackend, S: Into<ShapeStyle>>(
         &self,
-        chart: &mut ChartContext<
-            '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        >,
+        chart: &mut Chart<'_, DB>,
         style: S,
     ) {
         let arc = self.to_value();
@@ -129,16 +109,9 @@ where
     T: Number,
     T: HasValue<Output = f64>,
 {
-    pub fn draw<S: Into<ShapeStyle>>(
+    pub fn draw<DB: DrawingBackend, S: Into<ShapeStyle>>(
         &self,
-        chart: &mut ChartContext<
-            '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        >,
+        chart: &mut Chart<'_, DB>,
         style: S,
     ) {
         let segment = self.to_value();
@@ -158,16 +131,9 @@ where
     T: Number,
     T: HasValue<Output = f64>,
 {
-    pub fn draw<S: Into<ShapeStyle> + Clone>(
+    pub fn draw<DB: DrawingBackend, S: Into<ShapeStyle> + Clone>(
         &self,
-        chart: &mut ChartContext<
-            '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        >,
+        chart: &mut Chart<'_, DB>,
         style: S,
     ) {
         for seg in self.segments() {
@@ -176,6 +142,42 @@ where
     }
 }
 
+//-------------------------------------------------- PolygonWithHoles --------------------------------------------------
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    pub fn draw<DB: DrawingBackend, S: Into<ShapeStyle> + Clone>(
+        &self,
+        chart: &mut Chart<'_, DB>,
+        style: S,
+    ) {
+        for ring in self.rings() {
+            ring.draw(chart, style.clone());
+        }
+    }
+}
+
+//-------------------------------------------------- MultiPolygon --------------------------------------------------
+
+impl<T> MultiPolygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    pub fn draw<DB: DrawingBackend, S: Into<ShapeStyle> + Clone>(
+        &self,
+        chart: &mut Chart<'_, DB>,
+        style: S,
+    ) {
+        for polygon in self.polygons() {
+            polygon.draw(chart, style.clone());
+        }
+    }
+}
+
 //-------------------------------------------------- Pose --------------------------------------------------
 
 impl<T> Pose<T>
@@ -183,16 +185,9 @@ where
     T: Number,
     T: HasValue<Output = f64>,
 {
-    pub fn draw<S: Into<ShapeStyle>>(
+    pub fn draw<DB: DrawingBackend, S: Into<ShapeStyle>>(
         &self,
-        chart: &mut ChartContext<
-            '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        >,
+        chart: &mut Chart<'_, DB>,
         point_color: RGBColor,
         point_size: i32,
         line_style: S,
@@ -211,6 +206,25 @@ where
     }
 }
 
+//-------------------------------------------------- Transition --------------------------------------------------
+
+impl<T> Transition<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    pub fn draw<DB: DrawingBackend, S: Into<ShapeStyle>>(
+        &self,
+        chart: &mut Chart<'_, DB>,
+        style: S,
+    ) {
+        match self {
+            Transition::Segment(seg) => seg.draw(chart, style),
+            Transition::Arc(arc) => arc.draw(chart, style),
+        }
+    }
+}
+
 //-------------------------------------------------- Trajectory --------------------------------------------------
 
 impl<T> Trajectory<T>
@@ -218,21 +232,14 @@ where
     T: Number,
     T: HasValue<Output = f64>,
 {
-    pub fn draw<S: Into<ShapeStyle> + Clone>(
+    pub fn draw<DB: DrawingBackend, S: Into<ShapeStyle> + Clone>(
         &self,
-        chart: &mut ChartContext<
-            '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        >,
+        chart: &mut Chart<'_, DB>,
         style: S,
     ) {
         let traj = self.to_value();
         traj.start_rotation().draw(chart, style.clone());
-        traj.segment().draw(chart, style.clone());
+        traj.transition().draw(chart, style.clone());
         traj.finish_rotation().draw(chart, style);
     }
 }
@@ -244,16 +251,9 @@ where
     T: Number,
     T: HasValue<Output = f64>,
 {
-    pub fn draw<S: Into<ShapeStyle> + Clone>(
+    pub fn draw<DB: DrawingBackend, S: Into<ShapeStyle> + Clone>(
         &self,
-        chart: &mut ChartContext<
-            '_,
-            BitMapBackend<'_>,
-            Cartesian2d<
-                plotters::coord::types::RangedCoordf64,
-                plotters::coord::types::RangedCoordf64,
-            >,
-        >,
+        chart: &mut Chart<'_, DB>,
         style: S,
     ) {
         let path = self.to_value();
@@ -262,3 +262,26 @@ where
         }
     }
 }
+
+//-------------------------------------------------- ReedsShepp --------------------------------------------------
+
+impl<T> ReedsShepp<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    pub fn draw<DB: DrawingBackend, S: Into<ShapeStyle> + Clone>(
+        &self,
+        chart: &mut Chart<'_, DB>,
+        forward_style: S,
+        backward_style: S,
+    ) {
+        let traj = self.to_value();
+        for element in traj.elements().iter() {
+            match element.gear() {
+                Gear::Forward => element.motion().draw(chart, forward_style.clone()),
+                Gear::Backward => element.motion().draw(chart, backward_style.clone()),
+            }
+        }
+    }
+}
@@ -83,6 +83,20 @@ where
     }
 }
 
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for Point<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        transform.apply_to_point(self)
+    }
+}
+
 //-------------------------------------------------- Ops --------------------------------------------------
 
 //------------------------- Add -------------------------
@@ -506,6 +520,30 @@ where
     }
 }
 
+impl<T> Distance<T, PolygonWithHoles<T>> for Point<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+    T: Pow2<Output = <T as Mul>::Output>,
+    <T as Pow2>::Output: Root2<Output = T>,
+    <T as Mul>::Output: Div,
+    <<T as Mul>::Output as Div>::Output: Number,
+    T: Mul<<<T as Mul>::Output as Div>::Output, Output = T>,
+{
+    /// Distance to the closest boundary, the outer one or a hole.
+    fn distance(&self, other: &PolygonWithHoles<T>) -> T {
+        other
+            .rings()
+            .map(|ring| self.distance(ring))
+            .fold(None, |dst: Option<T>, d| match dst {
+                Some(dst) if dst <= d => Some(dst),
+                _ => Some(d),
+            })
+            .unwrap_or(T::ZERO)
+    }
+}
+
 impl<T> Distance<T, Circle<T>> for Point<T>
 where
     T: Number,
@@ -521,6 +559,30 @@ where
     }
 }
 
+impl<T> Distance<T, DirectedArc<T>> for Point<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Distance to the circle when the point faces the arc, to the closest end otherwise.
+    fn distance(&self, other: &DirectedArc<T>) -> T {
+        let (c, r) = (other.center(), other.radius().value());
+        let v = [self.x.value() - c.x.value(), self.y.value() - c.y.value()];
+        let (from, sweep) = other.range();
+        let angle = v[1].atan2(v[0]);
+        let d = if (angle - from).rem_euclid(2.0 * std::f64::consts::PI) <= sweep {
+            (v[0].hypot(v[1]) - r).abs()
+        } else {
+            [from, from + sweep]
+                .map(|a| (v[0] - r * a.cos()).hypot(v[1] - r * a.sin()))
+                .into_iter()
+                .fold(f64::INFINITY, f64::min)
+        };
+        T::ONE * d
+    }
+}
+
 //-------------------------------------------------- Projection --------------------------------------------------
 
 impl<T> Point<T>
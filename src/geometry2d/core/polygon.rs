@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::fmt::Display;
 use std::ops::*;
 
 use super::*;
@@ -12,12 +13,237 @@ pub struct Polygon<T: Number> {
     points: Vec<Point<T>>,
 }
 
+/// The reasons why a list of vertices does not describe a valid simple polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolygonError {
+    /// Fewer than 3 vertices were given.
+    TooFewVertices(usize),
+    /// The vertex at this index is equal to the next one (the last vertex is followed by the first).
+    DuplicateVertex(usize),
+    /// All the vertices are collinear.
+    ZeroArea,
+    /// The edges starting at these two vertex indices cross or overlap.
+    SelfIntersection(usize, usize),
+}
+
+impl Display for PolygonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolygonError::TooFewVertices(n) => {
+                write!(f, "polygon needs at least 3 vertices, got {}", n)
+            }
+            PolygonError::DuplicateVertex(i) => {
+                write!(f, "duplicate consecutive vertex at index {}", i)
+            }
+            PolygonError::ZeroArea => write!(f, "polygon has zero area"),
+            PolygonError::SelfIntersection(i, j) => {
+                write!(f, "edges {} and {} intersect each other", i, j)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PolygonError {}
+
 //-------------------------------------------------- New --------------------------------------------------
 
 impl<T: Number> Polygon<T> {
     pub fn points(&self) -> &Vec<Point<T>> {
         &self.points
     }
+
+    /// Creates a polygon from vertices known to be valid, keeping their order.
+    pub(crate) fn new_unchecked(points: Vec<Point<T>>) -> Self {
+        Self { points }
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    /// Creates a simple polygon from an ordered list of vertices (the closing vertex must not be repeated).
+    /// The vertices are reordered counter-clockwise if needed, keeping the first one in place.
+    pub fn new(points: Vec<Point<T>>) -> Result<Self, PolygonError> {
+        let n = points.len();
+        if n < 3 {
+            return Err(PolygonError::TooFewVertices(n));
+        }
+        for i in 0..n {
+            if points[i] == points[(i + 1) % n] {
+                return Err(PolygonError::DuplicateVertex(i));
+            }
+        }
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if edges_intersect(&points, i, j) {
+                    return Err(PolygonError::SelfIntersection(i, j));
+                }
+            }
+        }
+        if double_signed_area(&points).abs() <= <T as Mul>::Output::EPSILON {
+            return Err(PolygonError::ZeroArea);
+        }
+        let mut polygon = Self { points };
+        polygon.set_winding(Orientation::CounterClockWise);
+        Ok(polygon)
+    }
+}
+
+/// Twice the signed area of the polygon: positive when counter-clockwise.
+fn double_signed_area<T>(points: &[Point<T>]) -> <T as Mul>::Output
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    let mut area = <T as Mul>::Output::ZERO;
+    let mut previous = points.last().unwrap();
+    for current in points.iter() {
+        area += previous.x * current.y;
+        area -= current.x * previous.y;
+        previous = current;
+    }
+    area
+}
+
+/// Returns `true` if `p` lies on the segment `[a, b]`, knowing that the three points are collinear.
+fn on_segment<T: Number>(a: &Point<T>, b: &Point<T>, p: &Point<T>) -> bool {
+    p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+}
+
+/// Checks whether the edges starting at vertices `i` and `j` (`i < j`) intersect,
+/// ignoring the shared vertex of adjacent edges.
+fn edges_intersect<T>(points: &[Point<T>], i: usize, j: usize) -> bool
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    let n = points.len();
+    let (a0, a1) = (&points[i], &points[(i + 1) % n]);
+    let (b0, b1) = (&points[j], &points[(j + 1) % n]);
+
+    // Adjacent edges only intersect if they fold back onto each other
+    if j == i + 1 || (i == 0 && j == n - 1) {
+        let (shared, a, b) = if j == i + 1 {
+            (a1, a0, b1)
+        } else {
+            (a0, a1, b0)
+        };
+        return points_orientation(a, shared, b) == Orientation::Collinear
+            && (on_segment(shared, a, b) || on_segment(shared, b, a));
+    }
+
+    let o1 = points_orientation(a0, a1, b0);
+    let o2 = points_orientation(a0, a1, b1);
+    let o3 = points_orientation(b0, b1, a0);
+    let o4 = points_orientation(b0, b1, a1);
+
+    if o1 != o2
+        && o3 != o4
+        && [o1, o2, o3, o4]
+            .iter()
+            .all(|o| *o != Orientation::Collinear)
+    {
+        return true;
+    }
+    (o1 == Orientation::Collinear && on_segment(a0, a1, b0))
+        || (o2 == Orientation::Collinear && on_segment(a0, a1, b1))
+        || (o3 == Orientation::Collinear && on_segment(b0, b1, a0))
+        || (o4 == Orientation::Collinear && on_segment(b0, b1, a1))
+}
+
+//-------------------------------------------------- Area --------------------------------------------------
+
+impl<T> Polygon<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+    <T as Mul>::Output: Mul<f64, Output = <T as Mul>::Output>,
+{
+    /// Returns the area enclosed by the polygon, whatever its winding.
+    pub fn area(&self) -> <T as Mul>::Output {
+        double_signed_area(&self.points).abs() * 0.5
+    }
+}
+
+//-------------------------------------------------- Winding --------------------------------------------------
+
+impl<T> Polygon<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    /// Returns the order in which the vertices are stored.
+    pub fn winding(&self) -> Orientation {
+        let area = double_signed_area(&self.points);
+        if area > <T as Mul>::Output::ZERO {
+            Orientation::CounterClockWise
+        } else if area < <T as Mul>::Output::ZERO {
+            Orientation::ClockWise
+        } else {
+            Orientation::Collinear
+        }
+    }
+
+    /// Reorders the vertices to follow `winding`, keeping the first vertex in place.
+    pub fn set_winding(&mut self, winding: Orientation) {
+        let current = self.winding();
+        if current != winding
+            && current != Orientation::Collinear
+            && winding != Orientation::Collinear
+        {
+            self.points[1..].reverse();
+        }
+    }
+
+    pub fn with_winding(&self, winding: Orientation) -> Self {
+        let mut polygon = self.clone();
+        polygon.set_winding(winding);
+        polygon
+    }
+
+    /// Returns `true` if every turn goes the same way (collinear vertices are allowed).
+    pub fn is_convex(&self) -> bool {
+        self.is_convex_by(points_orientation)
+    }
+
+    /// Same as [`Polygon::is_convex`], with the choice of [`Predicates`]: with a tolerance, nearly
+    /// collinear vertices are allowed.
+    pub fn is_convex_with(&self, predicates: &Predicates<T>) -> bool
+    where
+        T: HasValue<Output = f64>,
+    {
+        self.is_convex_by(|p0, p1, p2| predicates.orientation(p0, p1, p2))
+    }
+
+    fn is_convex_by<F>(&self, orientation: F) -> bool
+    where
+        F: Fn(&Point<T>, &Point<T>, &Point<T>) -> Orientation,
+    {
+        let n = self.points.len();
+        let mut winding = None;
+        for i in 0..n {
+            let o = orientation(
+                &self.points[i],
+                &self.points[(i + 1) % n],
+                &self.points[(i + 2) % n],
+            );
+            if o == Orientation::Collinear {
+                continue;
+            }
+            match winding {
+                Some(previous) if previous != o => return false,
+                _ => winding = Some(o),
+            }
+        }
+        true
+    }
 }
 
 impl<T> Polygon<T>
@@ -32,7 +258,16 @@ where
     <<T as Mul>::Output as Div>::Output: Number,
     T: Mul<<<T as Mul>::Output as Div>::Output, Output = T>,
 {
-    pub fn convex_hull(mut points: Vec<Point<T>>) -> Option<Self> {
+    /// Returns the convex hull of the points, counter-clockwise from the lowest one.
+    pub fn convex_hull(points: Vec<Point<T>>) -> Option<Self> {
+        Self::convex_hull_by(points, points_orientation)
+    }
+
+    /// Graham scan, with the orientation predicate of the caller.
+    fn convex_hull_by<F>(mut points: Vec<Point<T>>, orientation: F) -> Option<Self>
+    where
+        F: Fn(&Point<T>, &Point<T>, &Point<T>) -> Orientation,
+    {
         if points.len() < 3 {
             return None;
         }
@@ -72,7 +307,7 @@ where
         while !points.is_empty() {
             let point = points.pop().unwrap();
             while hull.len() > 1
-                && points_orientation(&hull[hull.len() - 2], &hull[hull.len() - 1], &point)
+                && orientation(&hull[hull.len() - 2], &hull[hull.len() - 1], &point)
                     != Orientation::ClockWise
             {
                 hull.pop();
@@ -84,8 +319,18 @@ where
             return None;
         }
 
+        // The scan turns clockwise: reverse it, keeping the first point in place
+        hull[1..].reverse();
         Some(Self { points: hull })
     }
+
+    /// Same as [`Polygon::convex_hull`], with the choice of [`Predicates`].
+    pub fn convex_hull_with(points: Vec<Point<T>>, predicates: &Predicates<T>) -> Option<Self>
+    where
+        T: HasValue<Output = f64>,
+    {
+        Self::convex_hull_by(points, |p0, p1, p2| predicates.orientation(p0, p1, p2))
+    }
 }
 
 //--------------------------------------------------  --------------------------------------------------
@@ -146,6 +391,26 @@ where
     }
 }
 
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for Polygon<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self {
+            points: self
+                .points
+                .iter()
+                .map(|pt| transform.apply_to_point(pt))
+                .collect(),
+        }
+    }
+}
+
 //-------------------------------------------------- Ops --------------------------------------------------
 
 //------------------------- Add -------------------------
@@ -301,31 +566,171 @@ where
     }
 }
 
+//-------------------------------------------------- Width --------------------------------------------------
+
+impl<T> Polygon<T>
+where
+    T: Number + AngleFactory,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul,
+    <T as Mul>::Output: Number,
+    T: Pow2<Output = <T as Mul>::Output>,
+    <T as Pow2>::Output: Root2<Output = T>,
+    <T as Mul>::Output: Div,
+    <<T as Mul>::Output as Div>::Output: Number,
+    T: Mul<<<T as Mul>::Output as Div>::Output, Output = T>,
+{
+    /// Returns the minimum width of the polygon and the direction of the convex hull edge achieving it,
+    /// found by rotating calipers. Passes parallel to this direction are the fewest.
+    pub fn min_width(&self) -> Option<(T, Vector<T>)> {
+        let hull = Self::convex_hull(self.points.clone())?;
+        let points = hull.points();
+        let n = points.len();
+        // Twice the area of the triangle between the edge `i` and the point `j`
+        let area = |i: usize, j: usize| {
+            let (a, b, p) = (&points[i], &points[(i + 1) % n], &points[j % n]);
+            ((b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)).abs()
+        };
+
+        let mut best: Option<(T, Vector<T>)> = None;
+        let mut j = 1;
+        for i in 0..n {
+            // Antipodal point of the edge, moving forward with it
+            while area(i, j + 1) > area(i, j) {
+                j += 1;
+            }
+            let edge: Vector<T> = (&points[i], &points[(i + 1) % n]).into();
+            let length2 = edge.dx * edge.dx + edge.dy * edge.dy;
+            let width = edge.norm() * (area(i, j) / length2);
+            if best.as_ref().is_none_or(|(w, _)| width < *w) {
+                best = Some((width, edge));
+            }
+        }
+        best
+    }
+}
+
 //-------------------------------------------------- Contains --------------------------------------------------
 
-impl<T> Contains<Point<T>> for Polygon<T>
+/// The position of a point relative to a closed shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointPosition {
+    Inside,
+    OnBoundary,
+    Outside,
+}
+
+impl<T> Polygon<T>
 where
     T: Number,
-    T: std::ops::Mul,
-    <T as std::ops::Mul>::Output: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
 {
-    fn contains(&self, point: &Point<T>) -> bool {
-        let mut orientation = None;
-        let mut previous = self.points.last().unwrap();
+    /// Returns the number of times the boundary winds around `point` (positive counter-clockwise).
+    /// The result is meaningless for a point on the boundary.
+    pub fn winding_number(&self, point: &Point<T>) -> i32 {
+        self.winding_number_by(point, points_orientation)
+    }
 
+    fn winding_number_by<F>(&self, point: &Point<T>, orientation: F) -> i32
+    where
+        F: Fn(&Point<T>, &Point<T>, &Point<T>) -> Orientation,
+    {
+        let mut wn = 0;
+        let mut previous = self.points.last().unwrap();
         for current in self.points.iter() {
-            let new_o = points_orientation(previous, current, point);
-            match orientation {
-                Some(o) => {
-                    if new_o != o {
-                        return false;
-                    }
+            if previous.y <= point.y {
+                if current.y > point.y
+                    && orientation(previous, current, point) == Orientation::CounterClockWise
+                {
+                    wn += 1;
                 }
-                None => orientation = Some(new_o),
+            } else if current.y <= point.y
+                && orientation(previous, current, point) == Orientation::ClockWise
+            {
+                wn -= 1;
             }
             previous = current;
         }
-        true
+        wn
+    }
+
+    pub(crate) fn is_on_boundary(&self, point: &Point<T>) -> bool {
+        self.is_on_boundary_by(point, points_orientation)
+    }
+
+    fn is_on_boundary_by<F>(&self, point: &Point<T>, orientation: F) -> bool
+    where
+        F: Fn(&Point<T>, &Point<T>, &Point<T>) -> Orientation,
+    {
+        self.segments().iter().any(|seg| {
+            orientation(seg.first(), seg.second(), point) == Orientation::Collinear
+                && on_segment(seg.first(), seg.second(), point)
+        })
+    }
+
+    /// Same as [`Contains::contains`], with the choice of [`Predicates`]: with a tolerance, points
+    /// closer than it to the boundary are contained.
+    pub fn contains_with(&self, point: &Point<T>, predicates: &Predicates<T>) -> bool
+    where
+        T: HasValue<Output = f64>,
+    {
+        self.is_on_boundary_with(point, predicates)
+            || self.winding_number_by(point, |p0, p1, p2| predicates.orientation(p0, p1, p2)) != 0
+    }
+
+    /// Whether `point` is on the boundary, or closer than the tolerance of `predicates` to it.
+    pub(crate) fn is_on_boundary_with(&self, point: &Point<T>, predicates: &Predicates<T>) -> bool
+    where
+        T: HasValue<Output = f64>,
+    {
+        match predicates {
+            Predicates::Exact => {
+                self.is_on_boundary_by(point, |p0, p1, p2| predicates.orientation(p0, p1, p2))
+            }
+            Predicates::Epsilon(tolerance) => self.segments().iter().any(|seg| {
+                let (a, b) = (seg.first(), seg.second());
+                let [px, py] = [point.x.value() - a.x.value(), point.y.value() - a.y.value()];
+                let [dx, dy] = [b.x.value() - a.x.value(), b.y.value() - a.y.value()];
+                let t = ((px * dx + py * dy) / (dx * dx + dy * dy)).clamp(0.0, 1.0);
+                (px - t * dx).hypot(py - t * dy) <= tolerance.value()
+            }),
+        }
+    }
+}
+
+impl<T> Contains<Point<T>> for Polygon<T>
+where
+    T: Number,
+    T: std::ops::Mul,
+    <T as std::ops::Mul>::Output: Number,
+{
+    /// Points on the boundary are contained. Works for any simple polygon, convex or not.
+    fn contains(&self, point: &Point<T>) -> bool {
+        self.winding_number(point) != 0 || self.is_on_boundary(point)
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+    T: Pow2<Output = <T as Mul>::Output>,
+    <T as Pow2>::Output: Root2<Output = T>,
+    <T as Mul>::Output: Div,
+    <<T as Mul>::Output as Div>::Output: Number,
+    T: Mul<<<T as Mul>::Output as Div>::Output, Output = T>,
+{
+    /// Classifies `point`: it is on the boundary when closer than `tolerance` to an edge.
+    pub fn classify(&self, point: &Point<T>, tolerance: T) -> PointPosition {
+        if point.distance(self) <= tolerance {
+            PointPosition::OnBoundary
+        } else if self.winding_number(point) != 0 {
+            PointPosition::Inside
+        } else {
+            PointPosition::Outside
+        }
     }
 }
 
@@ -390,6 +795,17 @@ where
     }
 
     pub fn mapping(&self, direction: &Vector<T>, distance: T) -> Vec<Segment<T>> {
+        self.sweep(direction, distance, |line| {
+            inside_intervals(&line.intersection_to_polygon(self), self)
+        })
+    }
+
+    /// Sweeps lines `distance` apart along `direction` across the polygon, and collects the passes
+    /// given by `cut` for each line.
+    pub(crate) fn sweep<F>(&self, direction: &Vector<T>, distance: T, cut: F) -> Vec<Segment<T>>
+    where
+        F: Fn(&Line<T>) -> Vec<Segment<T>>,
+    {
         let mut segments = vec![];
         let first = self.mapping_first_point(direction);
         //
@@ -402,12 +818,62 @@ where
         loop {
             point = point + &v_per;
             let line = Line::new(point.clone(), direction.clone());
-            let list = line.intersection_to_polygon(self);
-            if list.len() < 2 {
+            if line.intersection_to_polygon(self).len() < 2 {
                 return segments;
             }
-            let segment = Segment::new(list.first().unwrap().clone(), list.last().unwrap().clone());
-            segments.push(segment);
+            segments.extend(cut(&line));
         }
     }
 }
+
+/// Pairs consecutive intersection points (sorted along a line) into the segments lying inside `shape`.
+pub(crate) fn inside_intervals<T, S>(points: &[Point<T>], shape: &S) -> Vec<Segment<T>>
+where
+    T: Number,
+    <T as HasValue>::Output: FromValue<usize>,
+    T: Div<<T as HasValue>::Output, Output = T>,
+    S: Contains<Point<T>>,
+{
+    let two = <T as HasValue>::Output::from_value(2);
+    points
+        .windows(2)
+        .filter(|pts| {
+            let middle = Point::new((pts[0].x + pts[1].x) / two, (pts[0].y + pts[1].y) / two);
+            shape.contains(&middle)
+        })
+        .map(|pts| Segment::new(pts[0].clone(), pts[1].clone()))
+        .collect()
+}
+
+//-------------------------------------------------- Mapping --------------------------------------------------
+
+impl<T> Mapping<T> for Polygon<T>
+where
+    T: Number,
+    //
+    <T as HasValue>::Output: FromValue<usize>,
+    //
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul<T, Output = <T as Pow2>::Output>,
+    T: Mul<<<T as Pow2>::Output as Div>::Output, Output = T>,
+    //
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: DivAssign<<T as HasValue>::Output>,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Div,
+    <<T as Pow2>::Output as Div>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+{
+    fn mapping(&self, direction: &Vector<T>, distance: T) -> Vec<Segment<T>> {
+        Polygon::mapping(self, direction, distance)
+    }
+
+    fn mapping_directions(&self) -> Vec<Vector<T>> {
+        self.segments().iter().map(|seg| seg.to_vector()).collect()
+    }
+    fn mapping_vertices(&self) -> Vec<Point<T>> {
+        self.points.clone()
+    }
+}
@@ -0,0 +1,311 @@
+use std::ops::*;
+
+use super::*;
+use sity::*;
+
+/// A 2D polygon with holes: an outer boundary and interior rings (exclusion zones).
+/// The outer boundary is stored counter-clockwise and the holes clockwise.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PolygonWithHoles<T: Number> {
+    /// The outer boundary.
+    outer: Polygon<T>,
+    /// The interior rings, assumed to be inside the outer boundary and disjoint.
+    holes: Vec<Polygon<T>>,
+}
+
+//-------------------------------------------------- New --------------------------------------------------
+
+impl<T: Number> PolygonWithHoles<T> {
+    pub fn outer(&self) -> &Polygon<T> {
+        &self.outer
+    }
+
+    pub fn holes(&self) -> &Vec<Polygon<T>> {
+        &self.holes
+    }
+
+    /// Returns the outer boundary followed by the holes.
+    pub fn rings(&self) -> impl Iterator<Item = &Polygon<T>> {
+        std::iter::once(&self.outer).chain(self.holes.iter())
+    }
+
+    pub fn segments(&self) -> Vec<Segment<T>> {
+        self.rings().flat_map(|ring| ring.segments()).collect()
+    }
+
+    /// Creates a polygon with holes from rings known to be correctly oriented.
+    pub(crate) fn new_unchecked(outer: Polygon<T>, holes: Vec<Polygon<T>>) -> Self {
+        Self { outer, holes }
+    }
+}
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    pub fn new(outer: Polygon<T>, holes: Vec<Polygon<T>>) -> Self {
+        let outer = outer.with_winding(Orientation::CounterClockWise);
+        let holes = holes
+            .iter()
+            .map(|hole| hole.with_winding(Orientation::ClockWise))
+            .collect();
+        Self { outer, holes }
+    }
+}
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+    <T as Mul>::Output: Mul<f64, Output = <T as Mul>::Output>,
+{
+    /// Returns the area of the outer boundary minus the area of the holes.
+    pub fn area(&self) -> <T as Mul>::Output {
+        self.holes
+            .iter()
+            .fold(self.outer.area(), |area, hole| area - hole.area())
+    }
+}
+
+impl<T> From<Polygon<T>> for PolygonWithHoles<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    fn from(value: Polygon<T>) -> Self {
+        Self::new(value, vec![])
+    }
+}
+
+//-------------------------------------------------- ToValue --------------------------------------------------
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Number,
+{
+    pub fn to_value(&self) -> PolygonWithHoles<<T as HasValue>::Output> {
+        PolygonWithHoles {
+            outer: self.outer.to_value(),
+            holes: self.holes.iter().map(|hole| hole.to_value()).collect(),
+        }
+    }
+}
+
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for PolygonWithHoles<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self {
+            outer: self.outer.transformed(transform),
+            holes: self
+                .holes
+                .iter()
+                .map(|hole| hole.transformed(transform))
+                .collect(),
+        }
+    }
+}
+
+//-------------------------------------------------- Contains --------------------------------------------------
+
+impl<T> Contains<Point<T>> for PolygonWithHoles<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    /// Points on the boundary of the outer ring or of a hole are contained.
+    fn contains(&self, point: &Point<T>) -> bool {
+        self.outer.contains(point)
+            && self
+                .holes
+                .iter()
+                .all(|hole| hole.winding_number(point) == 0 || hole.is_on_boundary(point))
+    }
+}
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    /// Same as [`Contains::contains`], with the choice of [`Predicates`]: with a tolerance, points
+    /// closer than it to the outer ring or to a hole are contained.
+    pub fn contains_with(&self, point: &Point<T>, predicates: &Predicates<T>) -> bool {
+        self.outer.contains_with(point, predicates)
+            && self.holes.iter().all(|hole| {
+                !hole.contains_with(point, predicates)
+                    || hole.is_on_boundary_with(point, predicates)
+            })
+    }
+}
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Number,
+    T: Mul,
+    <T as Mul>::Output: Number,
+    T: Pow2<Output = <T as Mul>::Output>,
+    <T as Pow2>::Output: Root2<Output = T>,
+    <T as Mul>::Output: Div,
+    <<T as Mul>::Output as Div>::Output: Number,
+    T: Mul<<<T as Mul>::Output as Div>::Output, Output = T>,
+{
+    /// Classifies `point`: it is on the boundary when closer than `tolerance` to the outer ring or a hole.
+    pub fn classify(&self, point: &Point<T>, tolerance: T) -> PointPosition {
+        if point.distance(self) <= tolerance {
+            PointPosition::OnBoundary
+        } else if self.contains(point) {
+            PointPosition::Inside
+        } else {
+            PointPosition::Outside
+        }
+    }
+}
+
+//-------------------------------------------------- Mapping --------------------------------------------------
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Number,
+    //
+    <T as HasValue>::Output: FromValue<usize>,
+    //
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul<T, Output = <T as Pow2>::Output>,
+    T: Mul<<<T as Pow2>::Output as Div>::Output, Output = T>,
+    //
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: DivAssign<<T as HasValue>::Output>,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Div,
+    <<T as Pow2>::Output as Div>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+{
+    /// Returns the passes covering the polygon, split around the holes.
+    pub fn mapping(&self, direction: &Vector<T>, distance: T) -> Vec<Segment<T>> {
+        self.outer.sweep(direction, distance, |line| {
+            inside_intervals(&line.intersection_to_polygon_with_holes(self), self)
+        })
+    }
+}
+
+/// Largest gap between the round corners of the grown holes and the exact arcs, as a fraction of
+/// the clearance.
+const CLEARANCE_TOLERANCE: f64 = 0.01;
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    //
+    <T as HasValue>::Output: FromValue<usize>,
+    //
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul<T, Output = <T as Pow2>::Output>,
+    T: Mul<<<T as Pow2>::Output as Div>::Output, Output = T>,
+    //
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: DivAssign<<T as HasValue>::Output>,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Div,
+    <<T as Pow2>::Output as Div>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+{
+    /// Returns the passes covering the polygon, grouped in the boustrophedon cells of the polygon
+    /// with its holes grown by `clearance`. Each cell is covered before the next one instead of
+    /// crossing the holes between the pieces of a pass, and the grown holes leave room to turn
+    /// at the ends of the passes.
+    pub fn mapping_cells(
+        &self,
+        direction: &Vector<T>,
+        distance: T,
+        clearance: T,
+    ) -> Vec<Vec<Segment<T>>> {
+        if self.holes.is_empty() {
+            return vec![self.mapping(direction, distance)];
+        }
+        let mut holes = MultiPolygon::new(
+            self.holes
+                .iter()
+                .map(|hole| PolygonWithHoles::from(hole.clone()))
+                .collect(),
+        );
+        // Nothing to grow without clearance, nor when the tolerance vanishes along with it
+        if clearance > T::ZERO {
+            let tolerance = clearance * CLEARANCE_TOLERANCE;
+            holes = holes
+                .offset(clearance, Corner::Round { tolerance })
+                .unwrap_or(holes);
+        }
+        MultiPolygon::from(self.outer.clone())
+            .difference(&holes)
+            .polygons()
+            .iter()
+            .flat_map(|polygon| polygon.boustrophedon_cells(direction))
+            .map(|cell| cell.mapping(direction, distance))
+            .filter(|passes| !passes.is_empty())
+            .collect()
+    }
+}
+
+impl<T> Mapping<T> for PolygonWithHoles<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    //
+    <T as HasValue>::Output: FromValue<usize>,
+    //
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul<T, Output = <T as Pow2>::Output>,
+    T: Mul<<<T as Pow2>::Output as Div>::Output, Output = T>,
+    //
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: DivAssign<<T as HasValue>::Output>,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Div,
+    <<T as Pow2>::Output as Div>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+{
+    fn mapping(&self, direction: &Vector<T>, distance: T) -> Vec<Segment<T>> {
+        PolygonWithHoles::mapping(self, direction, distance)
+    }
+
+    fn mapping_cells(
+        &self,
+        direction: &Vector<T>,
+        distance: T,
+        clearance: T,
+    ) -> Vec<Vec<Segment<T>>> {
+        PolygonWithHoles::mapping_cells(self, direction, distance, clearance)
+    }
+
+    fn mapping_zones(&self) -> Vec<Polygon<T>> {
+        self.holes.clone()
+    }
+
+    fn mapping_directions(&self) -> Vec<Vector<T>> {
+        self.segments().iter().map(|seg| seg.to_vector()).collect()
+    }
+    fn mapping_vertices(&self) -> Vec<Point<T>> {
+        self.outer.points().clone()
+    }
+}
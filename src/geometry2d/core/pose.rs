@@ -1,7 +1,7 @@
 use super::*;
 use sity::*;
 use std::fmt::Display;
-use std::ops::{Add, Sub};
+use std::ops::{Add, Mul, Sub};
 
 /// Represents a 2D pose with a position and orientation.
 ///
@@ -66,6 +66,23 @@ where
     }
 }
 
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for Pose<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self {
+            position: transform.apply_to_point(&self.position),
+            orientation: transform.apply_to_vector(&self.orientation),
+        }
+    }
+}
+
 //-------------------------------------------------- Ops --------------------------------------------------
 
 //------------------------- Add -------------------------
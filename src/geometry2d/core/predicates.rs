@@ -0,0 +1,335 @@
+use super::*;
+
+use sity::*;
+
+//-------------------------------------------------- Predicates --------------------------------------------------
+
+/// How the geometric predicates decide the sign of their determinant.
+///
+/// - `Exact`: the sign is the one of the exact determinant of the (floating point) inputs, using
+///   adaptive precision: the fast estimate is only refined when its error bound does not rule
+///   out another sign.
+/// - `Epsilon(tolerance)`: degenerate cases are widened to configurations closer than
+///   `tolerance`, a length in the unit of the coordinates: three points are collinear when the
+///   smallest height of their triangle is within `tolerance`, a point is on a circle when it is
+///   within `tolerance` of it.
+///
+/// They are taken by [`Polygon::convex_hull_with`], [`Polygon::contains_with`],
+/// [`Polygon::is_convex_with`], [`PolygonWithHoles::contains_with`] and [`IsParallelWith`]. The
+/// `classify` methods take their own tolerance, while oriented boxes, boolean operations and
+/// offsets use tolerances relative to the size of their inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Predicates<T: Number> {
+    #[default]
+    Exact,
+    Epsilon(T),
+}
+
+impl<T> Predicates<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    /// Returns the orientation of the turn `p0`, `p1`, `p2`. Points with NaN coordinates are
+    /// collinear.
+    pub fn orientation(&self, p0: &Point<T>, p1: &Point<T>, p2: &Point<T>) -> Orientation {
+        let (a, b, c) = (values(p0), values(p1), values(p2));
+        let det = match self {
+            Predicates::Exact => orient2d(a, b, c),
+            Predicates::Epsilon(tolerance) => {
+                let det = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+                let longest = [(a, b), (b, c), (c, a)]
+                    .map(|(p, q)| (q[0] - p[0]).hypot(q[1] - p[1]))
+                    .into_iter()
+                    .fold(0.0, f64::max);
+                if det.abs() <= tolerance.value() * longest {
+                    0.0
+                } else {
+                    det
+                }
+            }
+        };
+        sign_to_orientation(det)
+    }
+
+    /// Returns the position of `d` relative to the circle through `a`, `b` and `c`, whatever
+    /// their order. When `a`, `b` and `c` are collinear, the "circle" is their line and `d` is
+    /// never inside.
+    pub fn incircle(
+        &self,
+        a: &Point<T>,
+        b: &Point<T>,
+        c: &Point<T>,
+        d: &Point<T>,
+    ) -> PointPosition {
+        let turn = match self.orientation(a, b, c) {
+            Orientation::CounterClockWise => 1.0,
+            Orientation::ClockWise => -1.0,
+            Orientation::Collinear => {
+                return match self.orientation(a, b, d) {
+                    Orientation::Collinear => PointPosition::OnBoundary,
+                    _ => PointPosition::Outside,
+                };
+            }
+        };
+        // Positive inside the circle
+        let (a, b, c, d) = (values(a), values(b), values(c), values(d));
+        let det = match self {
+            Predicates::Exact => incircle(a, b, c, d) * turn,
+            Predicates::Epsilon(tolerance) => {
+                // Distance from `d` to the circumcircle
+                let (b, c, e) = (sub(b, a), sub(c, a), sub(d, a));
+                let det = 2.0 * (b[0] * c[1] - b[1] * c[0]);
+                let (lb, lc) = (b[0] * b[0] + b[1] * b[1], c[0] * c[0] + c[1] * c[1]);
+                let center = [(c[1] * lb - b[1] * lc) / det, (b[0] * lc - c[0] * lb) / det];
+                let radius = center[0].hypot(center[1]);
+                let gap = radius - (e[0] - center[0]).hypot(e[1] - center[1]);
+                if gap.abs() <= tolerance.value() {
+                    0.0
+                } else {
+                    gap
+                }
+            }
+        };
+        match sign_to_orientation(det) {
+            Orientation::CounterClockWise => PointPosition::Inside,
+            Orientation::ClockWise => PointPosition::Outside,
+            Orientation::Collinear => PointPosition::OnBoundary,
+        }
+    }
+
+    /// Whether `u` and `v` are parallel. With a tolerance, the tip of the shortest vector must be
+    /// within `tolerance` of the line of the longest one when both start at the same point.
+    pub fn is_parallel(&self, u: &Vector<T>, v: &Vector<T>) -> bool {
+        let origin = Point::new(T::ZERO, T::ZERO);
+        let (p, q) = (Point::new(u.dx, u.dy), Point::new(v.dx, v.dy));
+        match self {
+            Predicates::Exact => self.orientation(&origin, &p, &q) == Orientation::Collinear,
+            Predicates::Epsilon(tolerance) => {
+                let (u, v) = (values(&p), values(&q));
+                let longest = u[0].hypot(u[1]).max(v[0].hypot(v[1]));
+                (u[0] * v[1] - u[1] * v[0]).abs() <= tolerance.value() * longest
+            }
+        }
+    }
+}
+
+//-------------------------------------------------- Parallel --------------------------------------------------
+
+/// [`IsParallel`] with a choice of [`Predicates`].
+pub trait IsParallelWith<T, O>
+where
+    T: Number,
+{
+    fn is_parallel_with(&self, other: &O, predicates: &Predicates<T>) -> bool;
+}
+
+impl<T> IsParallelWith<T, Vector<T>> for Vector<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    fn is_parallel_with(&self, other: &Vector<T>, predicates: &Predicates<T>) -> bool {
+        predicates.is_parallel(self, other)
+    }
+}
+
+impl<T> IsParallelWith<T, Line<T>> for Line<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    fn is_parallel_with(&self, other: &Line<T>, predicates: &Predicates<T>) -> bool {
+        predicates.is_parallel(self.vector(), other.vector())
+    }
+}
+
+impl<T> IsParallelWith<T, Segment<T>> for Line<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    fn is_parallel_with(&self, other: &Segment<T>, predicates: &Predicates<T>) -> bool {
+        predicates.is_parallel(self.vector(), &direction(other))
+    }
+}
+
+impl<T> IsParallelWith<T, Segment<T>> for Segment<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    fn is_parallel_with(&self, other: &Segment<T>, predicates: &Predicates<T>) -> bool {
+        predicates.is_parallel(&direction(self), &direction(other))
+    }
+}
+
+impl<T> IsParallelWith<T, Line<T>> for Segment<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    fn is_parallel_with(&self, other: &Line<T>, predicates: &Predicates<T>) -> bool {
+        other.is_parallel_with(self, predicates)
+    }
+}
+
+fn direction<T: Number>(segment: &Segment<T>) -> Vector<T> {
+    Vector::new(
+        segment.second().x - segment.first().x,
+        segment.second().y - segment.first().y,
+    )
+}
+
+//-------------------------------------------------- Adaptive Precision --------------------------------------------------
+
+// Shewchuk, "Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric Predicates".
+// Expansions are sums of non-overlapping floats, by increasing magnitude, without zeros: their
+// sign is the one of their last component.
+
+type P = [f64; 2];
+
+/// Half the machine epsilon: the relative error of a rounded operation.
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const CCW_ERROR_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const ICC_ERROR_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+fn values<T>(p: &Point<T>) -> P
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    [p.x.value(), p.y.value()]
+}
+
+fn sub(a: P, b: P) -> P {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+/// Counter-clockwise for a positive sign. NaN is collinear.
+fn sign_to_orientation(det: f64) -> Orientation {
+    if det > 0.0 {
+        Orientation::CounterClockWise
+    } else if det < 0.0 {
+        Orientation::ClockWise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let bv = x - a;
+    let av = x - bv;
+    (x, (a - av) + (b - bv))
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+/// The expansion `e + b`.
+fn grow(e: &[f64], b: f64) -> Vec<f64> {
+    let mut res = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &x in e {
+        let (sum, error) = two_sum(q, x);
+        if error != 0.0 {
+            res.push(error);
+        }
+        q = sum;
+    }
+    if q != 0.0 {
+        res.push(q);
+    }
+    res
+}
+
+/// The expansion `e + f`.
+fn sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |acc, &x| grow(&acc, x))
+}
+
+/// The expansion `e * b`.
+fn scale(e: &[f64], b: f64) -> Vec<f64> {
+    e.iter().fold(vec![], |acc, &x| {
+        let (product, error) = two_product(x, b);
+        grow(&grow(&acc, error), product)
+    })
+}
+
+/// The exact product of the factors, as an expansion.
+fn product(factors: &[f64]) -> Vec<f64> {
+    factors.iter().fold(vec![1.0], |acc, &x| scale(&acc, x))
+}
+
+fn sign(e: &[f64]) -> f64 {
+    e.last().copied().unwrap_or(0.0)
+}
+
+/// Positive when `a`, `b`, `c` turn counter-clockwise, negative clockwise, zero when collinear.
+/// Only the sign is exact.
+fn orient2d(a: P, b: P, c: P) -> f64 {
+    let left = (a[0] - c[0]) * (b[1] - c[1]);
+    let right = (a[1] - c[1]) * (b[0] - c[0]);
+    let det = left - right;
+    if det.abs() > CCW_ERROR_BOUND * (left.abs() + right.abs()) || det.is_nan() {
+        return det;
+    }
+
+    // The exact determinant from the products of the coordinates
+    let terms = [
+        (1.0, a[0], b[1]),
+        (-1.0, a[0], c[1]),
+        (-1.0, a[1], b[0]),
+        (1.0, a[1], c[0]),
+        (1.0, b[0], c[1]),
+        (-1.0, b[1], c[0]),
+    ];
+    let exact = terms
+        .iter()
+        .fold(vec![], |acc, &(s, x, y)| sum(&acc, &product(&[s, x, y])));
+    sign(&exact)
+}
+
+/// Positive when `d` is inside the circle through `a`, `b`, `c` (counter-clockwise), negative
+/// outside, zero on it. Only the sign is exact.
+fn incircle(a: P, b: P, c: P, d: P) -> f64 {
+    let (ad, bd, cd) = (sub(a, d), sub(b, d), sub(c, d));
+    let lift = |p: P| p[0] * p[0] + p[1] * p[1];
+    let det = lift(ad) * (bd[0] * cd[1] - cd[0] * bd[1])
+        + lift(bd) * (cd[0] * ad[1] - ad[0] * cd[1])
+        + lift(cd) * (ad[0] * bd[1] - bd[0] * ad[1]);
+    let permanent = lift(ad) * ((bd[0] * cd[1]).abs() + (cd[0] * bd[1]).abs())
+        + lift(bd) * ((cd[0] * ad[1]).abs() + (ad[0] * cd[1]).abs())
+        + lift(cd) * ((ad[0] * bd[1]).abs() + (bd[0] * ad[1]).abs());
+    if det.abs() > ICC_ERROR_BOUND * permanent || det.is_nan() {
+        return det;
+    }
+
+    // The exact determinant of the rows `x y x²+y² 1`, expanded along its last column
+    let points = [a, b, c, d];
+    let mut exact = vec![];
+    for i in 0..4 {
+        let rows = (0..4)
+            .filter(|&k| k != i)
+            .map(|k| points[k])
+            .collect::<Vec<_>>();
+        let minor = if i % 2 == 0 { -1.0 } else { 1.0 };
+        for (p, q, r, s) in [
+            (0, 1, 2, 1.0),
+            (1, 2, 0, 1.0),
+            (2, 0, 1, 1.0),
+            (0, 2, 1, -1.0),
+            (1, 0, 2, -1.0),
+            (2, 1, 0, -1.0),
+        ] {
+            for k in 0..2 {
+                let factors = [minor * s, rows[p][0], rows[q][1], rows[r][k], rows[r][k]];
+                exact = sum(&exact, &product(&factors));
+            }
+        }
+    }
+    sign(&exact)
+}
@@ -0,0 +1,697 @@
+use super::*;
+use std::f64::consts::PI;
+use std::fmt::Display;
+use std::ops::Mul;
+
+use sity::*;
+
+//-------------------------------------------------- Gear --------------------------------------------------
+
+/// Represents the gear engaged while moving along a Reeds-Shepp element.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Gear {
+    /// The vehicle moves forward, its orientation is the tangent of the motion.
+    #[default]
+    Forward,
+    /// The vehicle moves backward, its orientation is opposite to the tangent of the motion.
+    Backward,
+}
+
+impl std::fmt::Display for Gear {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Gear::Forward => write!(f, "Forward"),
+            Gear::Backward => write!(f, "Backward"),
+        }
+    }
+}
+
+//-------------------------------------------------- Element --------------------------------------------------
+
+/// A single motion of a Reeds-Shepp trajectory: an arc or a segment travelled with a given gear.
+///
+/// The geometry of `motion` is always described in the travelling order (from its start point to
+/// its finish point), the `gear` only tells whether the vehicle faces the motion or not.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReedsSheppElement<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    /// The arc or segment travelled.
+    motion: Transition<T>,
+    /// The gear used to travel the motion.
+    gear: Gear,
+}
+
+impl<T> ReedsSheppElement<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    pub fn new(motion: Transition<T>, gear: Gear) -> Self {
+        Self { motion, gear }
+    }
+
+    pub fn motion(&self) -> &Transition<T> {
+        &self.motion
+    }
+
+    pub fn gear(&self) -> Gear {
+        self.gear
+    }
+
+    pub fn to_value(&self) -> ReedsSheppElement<<T as HasValue>::Output> {
+        ReedsSheppElement::new(self.motion.to_value(), self.gear)
+    }
+}
+
+impl<T> ReedsSheppElement<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    // DirectecArc
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    // Segment
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+{
+    pub fn length(&self) -> T {
+        self.motion.length()
+    }
+}
+
+impl<T> Transform<T> for ReedsSheppElement<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self::new(self.motion.transformed(transform), self.gear)
+    }
+}
+
+impl<T> Display for ReedsSheppElement<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.motion, self.gear)
+    }
+}
+
+//-------------------------------------------------- ReedsShepp --------------------------------------------------
+
+/// Represents a 2D Reeds-Shepp trajectory: a sequence of arcs and segments, each travelled
+/// forward or backward, going from the `start` pose to the `finish` pose.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReedsShepp<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    /// The starting pose of the trajectory.
+    start: Pose<T>,
+    /// The successive motions of the trajectory.
+    elements: Vec<ReedsSheppElement<T>>,
+    /// The ending pose of the trajectory.
+    finish: Pose<T>,
+}
+
+//-------------------------------------------------- New --------------------------------------------------
+
+impl<T> ReedsShepp<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    pub fn new(start: Pose<T>, elements: Vec<ReedsSheppElement<T>>, finish: Pose<T>) -> Self {
+        Self {
+            start,
+            elements,
+            finish,
+        }
+    }
+
+    pub fn start(&self) -> &Pose<T> {
+        &self.start
+    }
+
+    pub fn elements(&self) -> &Vec<ReedsSheppElement<T>> {
+        &self.elements
+    }
+
+    pub fn finish(&self) -> &Pose<T> {
+        &self.finish
+    }
+}
+
+//-------------------------------------------------- Length --------------------------------------------------
+
+impl<T> ReedsShepp<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    // DirectecArc
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    // Segment
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+{
+    pub fn length(&self) -> T {
+        let mut l = T::ZERO;
+        for element in self.elements.iter() {
+            l += element.length();
+        }
+        l
+    }
+}
+
+//-------------------------------------------------- Words --------------------------------------------------
+
+/// Steering of a normalized Reeds-Shepp element.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Steering {
+    Left,
+    Straight,
+    Right,
+}
+
+use Steering::{Left as L, Right as R, Straight as S};
+
+const WORDS: [&[Steering]; 18] = [
+    &[L, R, L],
+    &[R, L, R],
+    &[L, R, L, R],
+    &[R, L, R, L],
+    &[L, R, S, L],
+    &[R, L, S, R],
+    &[L, S, R, L],
+    &[R, S, L, R],
+    &[L, R, S, R],
+    &[R, L, S, L],
+    &[R, S, R, L],
+    &[L, S, L, R],
+    &[L, S, R],
+    &[R, S, L],
+    &[L, S, L],
+    &[R, S, R],
+    &[L, R, S, L, R],
+    &[R, L, S, R, L],
+];
+
+/// A normalized Reeds-Shepp word: the steering of each element and its signed length
+/// (radians for arcs, radius units for segments, negative when travelled backward).
+struct Word {
+    steering: &'static [Steering],
+    lengths: Vec<f64>,
+}
+
+const ZERO: f64 = 10.0 * f64::EPSILON;
+
+fn mod2pi(x: f64) -> f64 {
+    x.sin().atan2(x.cos())
+}
+
+fn polar(x: f64, y: f64) -> (f64, f64) {
+    ((x * x + y * y).sqrt(), y.atan2(x))
+}
+
+fn tau_omega(u: f64, v: f64, xi: f64, eta: f64, phi: f64) -> (f64, f64) {
+    let delta = mod2pi(u - v);
+    let a = u.sin() - delta.sin();
+    let b = u.cos() - delta.cos() - 1.0;
+    let t1 = (eta * a - xi * b).atan2(xi * a + eta * b);
+    let t2 = 2.0 * (delta.cos() - v.cos() - u.cos()) + 3.0;
+    let tau = if t2 < 0.0 {
+        mod2pi(t1 + PI)
+    } else {
+        mod2pi(t1)
+    };
+    let omega = mod2pi(tau - u + v - phi);
+    (tau, omega)
+}
+
+// Formulas numbered as in Reeds & Shepp, "Optimal paths for a car that goes both forwards
+// and backwards", 1990. Each returns the normalized lengths (t, u, v) of the base word.
+
+/// 8.1: L+ S+ L+
+fn lp_sp_lp(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (u, t) = polar(x - phi.sin(), y - 1.0 + phi.cos());
+    if t >= -ZERO {
+        let v = mod2pi(phi - t);
+        if v >= -ZERO {
+            return Some((t, u, v));
+        }
+    }
+    None
+}
+
+/// 8.2: L+ S+ R+
+fn lp_sp_rp(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (u1, t1) = polar(x + phi.sin(), y - 1.0 - phi.cos());
+    let u1 = u1 * u1;
+    if u1 >= 4.0 {
+        let u = (u1 - 4.0).sqrt();
+        let theta = 2.0_f64.atan2(u);
+        let t = mod2pi(t1 + theta);
+        let v = mod2pi(t - phi);
+        if t >= -ZERO && v >= -ZERO {
+            return Some((t, u, v));
+        }
+    }
+    None
+}
+
+/// 8.3: L+ R- L
+fn lp_rm_l(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (u1, theta) = polar(x - phi.sin(), y - 1.0 + phi.cos());
+    if u1 <= 4.0 {
+        let u = -2.0 * (0.25 * u1).asin();
+        let t = mod2pi(theta + 0.5 * u + PI);
+        let v = mod2pi(phi - t + u);
+        if t >= -ZERO && u <= ZERO {
+            return Some((t, u, v));
+        }
+    }
+    None
+}
+
+/// 8.7: L+ R+ L- R-
+fn lp_rup_lum_rm(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let xi = x + phi.sin();
+    let eta = y - 1.0 - phi.cos();
+    let rho = 0.25 * (2.0 + (xi * xi + eta * eta).sqrt());
+    if rho <= 1.0 {
+        let u = rho.acos();
+        let (t, v) = tau_omega(u, -u, xi, eta, phi);
+        if t >= -ZERO && v <= ZERO {
+            return Some((t, u, v));
+        }
+    }
+    None
+}
+
+/// 8.8: L+ R- L- R+
+fn lp_rum_lum_rp(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let xi = x + phi.sin();
+    let eta = y - 1.0 - phi.cos();
+    let rho = (20.0 - xi * xi - eta * eta) / 16.0;
+    if (0.0..=1.0).contains(&rho) {
+        let u = -rho.acos();
+        if u >= -0.5 * PI {
+            let (t, v) = tau_omega(u, u, xi, eta, phi);
+            if t >= -ZERO && v >= -ZERO {
+                return Some((t, u, v));
+            }
+        }
+    }
+    None
+}
+
+/// 8.9: L+ R-(pi/2) S- L-
+fn lp_rm_sm_lm(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (rho, theta) = polar(x - phi.sin(), y - 1.0 + phi.cos());
+    if rho >= 2.0 {
+        let r = (rho * rho - 4.0).sqrt();
+        let u = 2.0 - r;
+        let t = mod2pi(theta + r.atan2(-2.0));
+        let v = mod2pi(phi - 0.5 * PI - t);
+        if t >= -ZERO && u <= ZERO && v <= ZERO {
+            return Some((t, u, v));
+        }
+    }
+    None
+}
+
+/// 8.10: L+ R-(pi/2) S- R-
+fn lp_rm_sm_rm(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let xi = x + phi.sin();
+    let eta = y - 1.0 - phi.cos();
+    let (rho, theta) = polar(-eta, xi);
+    if rho >= 2.0 {
+        let t = theta;
+        let u = 2.0 - rho;
+        let v = mod2pi(t + 0.5 * PI - phi);
+        if t >= -ZERO && u <= ZERO && v <= ZERO {
+            return Some((t, u, v));
+        }
+    }
+    None
+}
+
+/// 8.11: L+ R-(pi/2) S- L-(pi/2) R+
+fn lp_rm_s_lm_rp(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let xi = x + phi.sin();
+    let eta = y - 1.0 - phi.cos();
+    let (rho, _) = polar(xi, eta);
+    if rho >= 2.0 {
+        let u = 4.0 - (rho * rho - 4.0).sqrt();
+        if u <= ZERO {
+            let t = mod2pi(((4.0 - u) * xi - 2.0 * eta).atan2(-2.0 * xi + (u - 4.0) * eta));
+            let v = mod2pi(t - phi);
+            if t >= -ZERO && v >= -ZERO {
+                return Some((t, u, v));
+            }
+        }
+    }
+    None
+}
+
+/// Returns every Reeds-Shepp word reaching the normalized goal `(x, y, phi)` from the origin.
+/// Each base formula is applied with the time-flip and reflection symmetries.
+fn words(x: f64, y: f64, phi: f64) -> Vec<Word> {
+    let mut res = vec![];
+    let mut push = |index: usize, lengths: Vec<f64>| {
+        res.push(Word {
+            steering: WORDS[index],
+            lengths,
+        })
+    };
+    let h = 0.5 * PI;
+    // Backward formulations
+    let xb = x * phi.cos() + y * phi.sin();
+    let yb = x * phi.sin() - y * phi.cos();
+
+    // CSC
+    if let Some((t, u, v)) = lp_sp_lp(x, y, phi) {
+        push(14, vec![t, u, v]);
+    }
+    if let Some((t, u, v)) = lp_sp_lp(-x, y, -phi) {
+        push(14, vec![-t, -u, -v]);
+    }
+    if let Some((t, u, v)) = lp_sp_lp(x, -y, -phi) {
+        push(15, vec![t, u, v]);
+    }
+    if let Some((t, u, v)) = lp_sp_lp(-x, -y, phi) {
+        push(15, vec![-t, -u, -v]);
+    }
+    if let Some((t, u, v)) = lp_sp_rp(x, y, phi) {
+        push(12, vec![t, u, v]);
+    }
+    if let Some((t, u, v)) = lp_sp_rp(-x, y, -phi) {
+        push(12, vec![-t, -u, -v]);
+    }
+    if let Some((t, u, v)) = lp_sp_rp(x, -y, -phi) {
+        push(13, vec![t, u, v]);
+    }
+    if let Some((t, u, v)) = lp_sp_rp(-x, -y, phi) {
+        push(13, vec![-t, -u, -v]);
+    }
+
+    // CCC
+    if let Some((t, u, v)) = lp_rm_l(x, y, phi) {
+        push(0, vec![t, u, v]);
+    }
+    if let Some((t, u, v)) = lp_rm_l(-x, y, -phi) {
+        push(0, vec![-t, -u, -v]);
+    }
+    if let Some((t, u, v)) = lp_rm_l(x, -y, -phi) {
+        push(1, vec![t, u, v]);
+    }
+    if let Some((t, u, v)) = lp_rm_l(-x, -y, phi) {
+        push(1, vec![-t, -u, -v]);
+    }
+    if let Some((t, u, v)) = lp_rm_l(xb, yb, phi) {
+        push(0, vec![v, u, t]);
+    }
+    if let Some((t, u, v)) = lp_rm_l(-xb, yb, -phi) {
+        push(0, vec![-v, -u, -t]);
+    }
+    if let Some((t, u, v)) = lp_rm_l(xb, -yb, -phi) {
+        push(1, vec![v, u, t]);
+    }
+    if let Some((t, u, v)) = lp_rm_l(-xb, -yb, phi) {
+        push(1, vec![-v, -u, -t]);
+    }
+
+    // CCCC
+    if let Some((t, u, v)) = lp_rup_lum_rm(x, y, phi) {
+        push(2, vec![t, u, -u, v]);
+    }
+    if let Some((t, u, v)) = lp_rup_lum_rm(-x, y, -phi) {
+        push(2, vec![-t, -u, u, -v]);
+    }
+    if let Some((t, u, v)) = lp_rup_lum_rm(x, -y, -phi) {
+        push(3, vec![t, u, -u, v]);
+    }
+    if let Some((t, u, v)) = lp_rup_lum_rm(-x, -y, phi) {
+        push(3, vec![-t, -u, u, -v]);
+    }
+    if let Some((t, u, v)) = lp_rum_lum_rp(x, y, phi) {
+        push(2, vec![t, u, u, v]);
+    }
+    if let Some((t, u, v)) = lp_rum_lum_rp(-x, y, -phi) {
+        push(2, vec![-t, -u, -u, -v]);
+    }
+    if let Some((t, u, v)) = lp_rum_lum_rp(x, -y, -phi) {
+        push(3, vec![t, u, u, v]);
+    }
+    if let Some((t, u, v)) = lp_rum_lum_rp(-x, -y, phi) {
+        push(3, vec![-t, -u, -u, -v]);
+    }
+
+    // CCSC
+    if let Some((t, u, v)) = lp_rm_sm_lm(x, y, phi) {
+        push(4, vec![t, -h, u, v]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_lm(-x, y, -phi) {
+        push(4, vec![-t, h, -u, -v]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_lm(x, -y, -phi) {
+        push(5, vec![t, -h, u, v]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_lm(-x, -y, phi) {
+        push(5, vec![-t, h, -u, -v]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_rm(x, y, phi) {
+        push(8, vec![t, -h, u, v]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_rm(-x, y, -phi) {
+        push(8, vec![-t, h, -u, -v]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_rm(x, -y, -phi) {
+        push(9, vec![t, -h, u, v]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_rm(-x, -y, phi) {
+        push(9, vec![-t, h, -u, -v]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_lm(xb, yb, phi) {
+        push(6, vec![v, u, -h, t]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_lm(-xb, yb, -phi) {
+        push(6, vec![-v, -u, h, -t]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_lm(xb, -yb, -phi) {
+        push(7, vec![v, u, -h, t]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_lm(-xb, -yb, phi) {
+        push(7, vec![-v, -u, h, -t]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_rm(xb, yb, phi) {
+        push(10, vec![v, u, -h, t]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_rm(-xb, yb, -phi) {
+        push(10, vec![-v, -u, h, -t]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_rm(xb, -yb, -phi) {
+        push(11, vec![v, u, -h, t]);
+    }
+    if let Some((t, u, v)) = lp_rm_sm_rm(-xb, -yb, phi) {
+        push(11, vec![-v, -u, h, -t]);
+    }
+
+    // CCSCC
+    if let Some((t, u, v)) = lp_rm_s_lm_rp(x, y, phi) {
+        push(16, vec![t, -h, u, -h, v]);
+    }
+    if let Some((t, u, v)) = lp_rm_s_lm_rp(-x, y, -phi) {
+        push(16, vec![-t, h, -u, h, -v]);
+    }
+    if let Some((t, u, v)) = lp_rm_s_lm_rp(x, -y, -phi) {
+        push(17, vec![t, -h, u, -h, v]);
+    }
+    if let Some((t, u, v)) = lp_rm_s_lm_rp(-x, -y, phi) {
+        push(17, vec![-t, h, -u, h, -v]);
+    }
+
+    res
+}
+
+//--------------------------------------------------  --------------------------------------------------
+
+impl<T> ReedsShepp<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    /// Builds the geometric elements of a normalized word, starting at `start` with a turning
+    /// radius of `radius`. Elements of null length are dropped.
+    fn build(start: &Pose<T>, finish: &Pose<T>, radius: T, word: &Word) -> Self {
+        let r = radius.value();
+        let position = start.position().to_value();
+        let orientation = start.orientation().to_value();
+        let (mut x, mut y) = (position.x, position.y);
+        let mut heading = orientation.dy.atan2(orientation.dx);
+
+        let mut elements = vec![];
+        for (steering, length) in word.steering.iter().zip(word.lengths.iter()) {
+            let gear = if *length >= 0.0 {
+                Gear::Forward
+            } else {
+                Gear::Backward
+            };
+            if length.abs() <= ZERO {
+                continue;
+            }
+            let from = Point::new(T::ONE * x, T::ONE * y);
+            match steering {
+                Steering::Straight => {
+                    x += r * length * heading.cos();
+                    y += r * length * heading.sin();
+                    let to = Point::new(T::ONE * x, T::ONE * y);
+                    let motion = Transition::Segment(Segment::new(from, to));
+                    elements.push(ReedsSheppElement::new(motion, gear));
+                }
+                Steering::Left | Steering::Right => {
+                    let (cx, cy, direction) = if *steering == Steering::Left {
+                        let direction = if gear == Gear::Forward {
+                            Direction::CounterClockWise
+                        } else {
+                            Direction::ClockWise
+                        };
+                        (x - r * heading.sin(), y + r * heading.cos(), direction)
+                    } else {
+                        let direction = if gear == Gear::Forward {
+                            Direction::ClockWise
+                        } else {
+                            Direction::CounterClockWise
+                        };
+                        (x + r * heading.sin(), y - r * heading.cos(), direction)
+                    };
+                    let center = Point::new(T::ONE * cx, T::ONE * cy);
+                    let circle = DirectedCircle::new(Circle::new(center, radius), direction);
+                    let start_angle = Radian::new((y - cy).atan2(x - cx));
+                    let arc = DirectedArc::new(circle, start_angle, Radian::new(length.abs()));
+                    let to = arc.finish_point().to_value();
+                    x = to.x;
+                    y = to.y;
+                    if *steering == Steering::Left {
+                        heading += length;
+                    } else {
+                        heading -= length;
+                    }
+                    elements.push(ReedsSheppElement::new(Transition::Arc(arc), gear));
+                }
+            }
+        }
+        Self::new(start.clone(), elements, finish.clone())
+    }
+
+    /// Returns every Reeds-Shepp trajectory joining `start` to `finish` with the given turning radius.
+    pub fn create_all(start: &Pose<T>, finish: &Pose<T>, radius: T) -> Vec<Self> {
+        let r = radius.value();
+        let p0 = start.position().to_value();
+        let o0 = start.orientation().to_value();
+        let p1 = finish.position().to_value();
+        let o1 = finish.orientation().to_value();
+        let h0 = o0.dy.atan2(o0.dx);
+        let h1 = o1.dy.atan2(o1.dx);
+        // Goal expressed in the start frame and normalized by the radius
+        let dx = p1.x - p0.x;
+        let dy = p1.y - p0.y;
+        let x = (h0.cos() * dx + h0.sin() * dy) / r;
+        let y = (-h0.sin() * dx + h0.cos() * dy) / r;
+        let phi = mod2pi(h1 - h0);
+
+        words(x, y, phi)
+            .iter()
+            .map(|word| Self::build(start, finish, radius, word))
+            .collect()
+    }
+
+    /// Returns the shortest Reeds-Shepp trajectory joining `start` to `finish`.
+    pub fn create(start: &Pose<T>, finish: &Pose<T>, radius: T) -> Option<Self>
+    where
+        T: Pow2,
+        <T as Pow2>::Output: Number,
+        <T as Pow2>::Output: Root2<Output = T>,
+    {
+        let mut length = T::ZERO;
+        let mut res = None;
+
+        for traj in Self::create_all(start, finish, radius) {
+            let l = traj.length();
+            if res.is_none() || l < length {
+                length = l;
+                res = Some(traj)
+            }
+        }
+
+        res
+    }
+}
+
+//-------------------------------------------------- ToValue --------------------------------------------------
+
+impl<T> ReedsShepp<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    pub fn to_value(&self) -> ReedsShepp<<T as HasValue>::Output> {
+        ReedsShepp {
+            start: self.start.to_value(),
+            elements: self
+                .elements
+                .iter()
+                .map(|element| element.to_value())
+                .collect(),
+            finish: self.finish.to_value(),
+        }
+    }
+}
+
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for ReedsShepp<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self {
+            start: self.start.transformed(transform),
+            elements: self
+                .elements
+                .iter()
+                .map(|element| element.transformed(transform))
+                .collect(),
+            finish: self.finish.transformed(transform),
+        }
+    }
+}
+
+//-------------------------------------------------- Display --------------------------------------------------
+
+impl<T> Display for ReedsShepp<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ReedsShepp({}", self.start)?;
+        for element in self.elements.iter() {
+            write!(f, ", {}", element)?;
+        }
+        write!(f, ", {})", self.finish)
+    }
+}
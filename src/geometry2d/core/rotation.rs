@@ -1,5 +1,6 @@
 /// Represents the direction of rotation in 2D geometry.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     /// Rotation in the clockwise direction.
     ClockWise,
@@ -13,6 +14,16 @@ impl Default for Direction {
     }
 }
 
+impl Direction {
+    /// Returns the opposite direction of rotation.
+    pub fn reversed(&self) -> Self {
+        match self {
+            Direction::ClockWise => Direction::CounterClockWise,
+            Direction::CounterClockWise => Direction::ClockWise,
+        }
+    }
+}
+
 impl std::fmt::Display for Direction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
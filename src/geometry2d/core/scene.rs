@@ -0,0 +1,386 @@
+//! A scene of styled and labelled shapes, drawn in a view fitted to their bounds, with a legend
+//! and axes labelled with the unit of the shapes.
+
+use super::plot::Chart;
+use super::*;
+use plotters::coord::Shift;
+use plotters::prelude::{
+    BitMapBackend, ChartBuilder, Color, DrawingArea, DrawingAreaErrorKind, DrawingBackend,
+    IntoDrawingArea, PathElement, RGBColor, SVGBackend, SeriesLabelPosition, ShapeStyle, BLACK,
+    WHITE,
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use sity::*;
+
+/// The margin around the chart, in pixels.
+const MARGIN: u32 = 10;
+/// The size of the areas of the axis labels, in pixels.
+const LABEL_AREA: u32 = 50;
+/// The height of the caption, in pixels.
+const CAPTION: u32 = 40;
+
+//-------------------------------------------------- Error --------------------------------------------------
+
+#[derive(Debug)]
+pub enum SceneError {
+    /// The file extension is neither `png` nor `svg`.
+    UnknownFormat(String),
+    /// The backend failed to draw.
+    Drawing(String),
+}
+
+impl Display for SceneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SceneError::UnknownFormat(extension) => {
+                write!(
+                    f,
+                    "unknown image format `{}`, expected png or svg",
+                    extension
+                )
+            }
+            SceneError::Drawing(message) => write!(f, "drawing failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SceneError {}
+
+impl<E: std::error::Error + Send + Sync> From<DrawingAreaErrorKind<E>> for SceneError {
+    fn from(value: DrawingAreaErrorKind<E>) -> Self {
+        Self::Drawing(value.to_string())
+    }
+}
+
+//-------------------------------------------------- Style --------------------------------------------------
+
+/// How a shape of a scene is drawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    pub color: RGBColor,
+    /// The width of the lines, in pixels.
+    pub width: u32,
+    /// The radius of the points, in pixels.
+    pub size: u32,
+}
+
+impl Style {
+    pub fn new(color: RGBColor, width: u32, size: u32) -> Self {
+        Self { color, width, size }
+    }
+
+    fn line(&self) -> ShapeStyle {
+        self.color.stroke_width(self.width)
+    }
+}
+
+impl From<RGBColor> for Style {
+    fn from(color: RGBColor) -> Self {
+        Self::new(color, 2, 4)
+    }
+}
+
+//-------------------------------------------------- Shape --------------------------------------------------
+
+/// A shape of a scene, with the values of its numbers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SceneShape {
+    Point(Point<f64>),
+    Circle(Circle<f64>),
+    Arc(DirectedArc<f64>),
+    Segment(Segment<f64>),
+    Polygon(Polygon<f64>),
+    PolygonWithHoles(PolygonWithHoles<f64>),
+    MultiPolygon(MultiPolygon<f64>),
+    /// A pose, drawn as its position and its orientation vector.
+    Pose(Pose<f64>),
+    Trajectory(Trajectory<f64>),
+    Path(Path<f64>),
+}
+
+impl SceneShape {
+    /// The bounds of the shape, `None` when it is empty.
+    pub fn aabb(&self) -> Option<Aabb<f64>> {
+        match self {
+            SceneShape::Point(p) => Some(p.aabb()),
+            SceneShape::Circle(c) => Some(c.aabb()),
+            SceneShape::Arc(a) => Some(a.aabb()),
+            SceneShape::Segment(s) => Some(s.aabb()),
+            SceneShape::Polygon(p) => Some(p.aabb()),
+            SceneShape::PolygonWithHoles(p) => Some(p.outer().aabb()),
+            SceneShape::MultiPolygon(m) => m
+                .polygons()
+                .iter()
+                .map(|p| p.outer().aabb())
+                .reduce(|a, b| a.union(&b)),
+            SceneShape::Pose(p) => {
+                Aabb::from_points(&[p.position().clone(), p.position() + p.orientation()])
+            }
+            SceneShape::Trajectory(t) => Some(t.aabb()),
+            SceneShape::Path(p) => {
+                if p.trajectories().is_empty() {
+                    None
+                } else {
+                    Some(p.aabb())
+                }
+            }
+        }
+    }
+
+    pub fn draw<DB: DrawingBackend>(&self, chart: &mut Chart<'_, DB>, style: &Style) {
+        match self {
+            SceneShape::Point(p) => p.draw(chart, style.color, style.size as i32),
+            SceneShape::Circle(c) => c.draw(chart, style.line()),
+            SceneShape::Arc(a) => a.draw(chart, style.line()),
+            SceneShape::Segment(s) => s.draw(chart, style.line()),
+            SceneShape::Polygon(p) => p.draw(chart, style.line()),
+            SceneShape::PolygonWithHoles(p) => p.draw(chart, style.line()),
+            SceneShape::MultiPolygon(m) => m.draw(chart, style.line()),
+            SceneShape::Pose(p) => p.draw(chart, style.color, style.size as i32, style.line(), 1.0),
+            SceneShape::Trajectory(t) => t.draw(chart, style.line()),
+            SceneShape::Path(p) => p.draw(chart, style.line()),
+        }
+    }
+}
+
+/// A shape that can be added to a scene in numbers of type `T`.
+pub trait ToSceneShape<T> {
+    fn to_scene_shape(&self) -> SceneShape;
+}
+
+macro_rules! to_scene_shape {
+    ($($variant:ident: $shape:ident),*) => {
+        $(
+            impl<T> ToSceneShape<T> for $shape<T>
+            where
+                T: Number,
+                T: HasValue<Output = f64>,
+            {
+                fn to_scene_shape(&self) -> SceneShape {
+                    SceneShape::$variant(self.to_value())
+                }
+            }
+        )*
+    };
+}
+
+to_scene_shape!(
+    Point: Point,
+    Circle: Circle,
+    Arc: DirectedArc,
+    Segment: Segment,
+    Polygon: Polygon,
+    PolygonWithHoles: PolygonWithHoles,
+    MultiPolygon: MultiPolygon,
+    Pose: Pose,
+    Trajectory: Trajectory,
+    Path: Path
+);
+
+//-------------------------------------------------- Scene --------------------------------------------------
+
+/// Shapes to draw together, in numbers of type `T`.
+///
+/// The view fits the bounds of the shapes, with a padding, and keeps the same scale on both axes.
+/// The axes are labelled with the unit of `T` and the labelled shapes are listed in a legend.
+///
+/// ```ignore
+/// Scene::new()
+///     .add_labelled(&polygon, BLACK, "field")
+///     .add_labelled(&path, Style::new(BLUE, 2, 4), "path")
+///     .save("path.svg")?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scene<T> {
+    items: Vec<(SceneShape, Style, Option<String>)>,
+    caption: Option<String>,
+    padding: Option<f64>,
+    size: (u32, u32),
+    phantom: PhantomData<T>,
+}
+
+impl<T> Default for Scene<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: UnitName,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Scene<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: UnitName,
+{
+    /// An empty scene of 1000 x 1000 pixels.
+    pub fn new() -> Self {
+        Self {
+            items: vec![],
+            caption: None,
+            padding: None,
+            size: (1000, 1000),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn add<S: ToSceneShape<T>>(&mut self, shape: &S, style: impl Into<Style>) -> &mut Self {
+        self.items
+            .push((shape.to_scene_shape(), style.into(), None));
+        self
+    }
+
+    /// Adds a shape listed in the legend with `label`.
+    pub fn add_labelled<S: ToSceneShape<T>>(
+        &mut self,
+        shape: &S,
+        style: impl Into<Style>,
+        label: &str,
+    ) -> &mut Self {
+        self.items.push((
+            shape.to_scene_shape(),
+            style.into(),
+            Some(label.to_string()),
+        ));
+        self
+    }
+
+    pub fn caption(&mut self, caption: &str) -> &mut Self {
+        self.caption = Some(caption.to_string());
+        self
+    }
+
+    /// Sets the space around the shapes. By default, it is 5% of the largest side of their
+    /// bounds.
+    pub fn padding(&mut self, padding: T) -> &mut Self {
+        self.padding = Some(padding.value());
+        self
+    }
+
+    /// Sets the size of the image, in pixels.
+    pub fn size(&mut self, width: u32, height: u32) -> &mut Self {
+        self.size = (width, height);
+        self
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = &SceneShape> {
+        self.items.iter().map(|(shape, _, _)| shape)
+    }
+
+    /// Returns the extent of the view: the bounds of the shapes with the padding, widened to the
+    /// proportions of the chart. An empty scene shows `[-1, 1]` on both axes.
+    pub fn view(&self) -> Aabb<f64> {
+        let bounds = self
+            .items
+            .iter()
+            .filter_map(|(shape, _, _)| shape.aabb())
+            .reduce(|a, b| a.union(&b));
+        let Some(bounds) = bounds else {
+            return Aabb::new(Point::new(-1.0, -1.0), Point::new(1.0, 1.0));
+        };
+        let largest = bounds.width().max(bounds.height());
+        let padding = self
+            .padding
+            .unwrap_or(if largest > 0.0 { 0.05 * largest } else { 1.0 });
+        let bounds = bounds.expanded(padding);
+
+        // Same scale on both axes
+        let (width, height) = self.chart_size();
+        let scale = (bounds.width() / width).max(bounds.height() / height);
+        let center = Point::new(
+            (bounds.min().x + bounds.max().x) / 2.0,
+            (bounds.min().y + bounds.max().y) / 2.0,
+        );
+        let (dx, dy) = (scale * width / 2.0, scale * height / 2.0);
+        Aabb::new(
+            Point::new(center.x - dx, center.y - dy),
+            Point::new(center.x + dx, center.y + dy),
+        )
+    }
+
+    /// The size of the plotting area, in pixels.
+    fn chart_size(&self) -> (f64, f64) {
+        let caption = if self.caption.is_some() { CAPTION } else { 0 };
+        let width = self.size.0.saturating_sub(2 * MARGIN + LABEL_AREA).max(1);
+        let height = self
+            .size
+            .1
+            .saturating_sub(2 * MARGIN + LABEL_AREA + caption)
+            .max(1);
+        (width as f64, height as f64)
+    }
+
+    /// Draws the scene on a drawing area of any backend.
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        root: &DrawingArea<DB, Shift>,
+    ) -> Result<(), SceneError> {
+        root.fill(&WHITE)?;
+        let view = self.view();
+        let mut builder = ChartBuilder::on(root);
+        builder
+            .margin(MARGIN)
+            .x_label_area_size(LABEL_AREA)
+            .y_label_area_size(LABEL_AREA);
+        if let Some(caption) = &self.caption {
+            builder.caption(caption, ("sans-serif", CAPTION / 2));
+        }
+        let mut chart: Chart<'_, DB> =
+            builder.build_cartesian_2d(view.min().x..view.max().x, view.min().y..view.max().y)?;
+
+        let unit = T::unit_name();
+        let axis = |name: &str| {
+            if unit.is_empty() {
+                name.to_string()
+            } else {
+                format!("{} ({})", name, unit)
+            }
+        };
+        chart
+            .configure_mesh()
+            .x_desc(axis("x"))
+            .y_desc(axis("y"))
+            .draw()?;
+
+        for (shape, style, label) in self.items.iter() {
+            shape.draw(&mut chart, style);
+            if let Some(label) = label {
+                let line = style.line();
+                chart
+                    .draw_series(std::iter::empty::<PathElement<(f64, f64)>>())?
+                    .label(label)
+                    .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], line));
+            }
+        }
+        if self.items.iter().any(|(_, _, label)| label.is_some()) {
+            chart
+                .configure_series_labels()
+                .position(SeriesLabelPosition::UpperRight)
+                .background_style(WHITE.mix(0.8))
+                .border_style(BLACK)
+                .draw()?;
+        }
+        root.present()?;
+        Ok(())
+    }
+
+    /// Renders the scene to a PNG or SVG file, depending on the extension of `path`.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<(), SceneError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match extension.as_str() {
+            "png" => self.draw(&BitMapBackend::new(path, self.size).into_drawing_area()),
+            "svg" => self.draw(&SVGBackend::new(path, self.size).into_drawing_area()),
+            _ => Err(SceneError::UnknownFormat(extension)),
+        }
+    }
+}
@@ -99,6 +99,25 @@ where
     }
 }
 
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for Segment<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self {
+            points: (
+                transform.apply_to_point(self.first()),
+                transform.apply_to_point(self.second()),
+            ),
+        }
+    }
+}
+
 //-------------------------------------------------- Ops --------------------------------------------------
 
 //------------------------- Add -------------------------
@@ -420,7 +439,9 @@ where
             Some(_) => T::ZERO,
             None => self
                 .distance(other.first())
-                .min(self.distance(other.second())),
+                .min(self.distance(other.second()))
+                .min(other.distance(self.first()))
+                .min(other.distance(self.second())),
         }
     }
 }
@@ -511,6 +532,21 @@ where
     }
 }
 
+impl<T> Segment<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    pub fn intersection_to_circle(&self, other: &Circle<T>) -> CircleIntersection<T> {
+        other.intersection_to_segment(self)
+    }
+
+    pub fn intersection_to_arc(&self, other: &DirectedArc<T>) -> CircleIntersection<T> {
+        other.intersection_to_segment(self)
+    }
+}
+
 //-------------------------------------------------- Display --------------------------------------------------
 
 impl<T: Number + Display> Display for Segment<T> {
@@ -0,0 +1,498 @@
+//! Serialization of the geometry, with the `serde` feature.
+//!
+//! Every shape is serialized with the unit of its numbers, e.g. `{"unit":"m","x":1.0,"y":2.0}`
+//! for a `Point` in metres, and deserializing checks that this unit is the one of the target type.
+//! Numbers are written in this unit; angles are in radians.
+//!
+//! Only plain numbers (`f64`) and lengths in metres, with any prefix, implement [`UnitName`]:
+//! shapes in other units cannot be serialized.
+
+use super::*;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ops::Mul;
+
+use sity::*;
+
+/// The serialized form of a shape: its unit, along with its numbers in this unit.
+#[derive(Serialize, Deserialize)]
+struct Tagged<R> {
+    unit: String,
+    #[serde(flatten)]
+    data: R,
+}
+
+/// A shape with numbers in `T`, and its serialized form `Self::Repr` with plain numbers.
+trait Represent<T>: Sized
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr;
+
+    fn to_repr(&self) -> Self::Repr;
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, String>;
+
+    fn serialize_tagged<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: UnitName,
+        Self::Repr: Serialize,
+    {
+        Tagged {
+            unit: T::unit_name(),
+            data: self.to_repr(),
+        }
+        .serialize(serializer)
+    }
+
+    fn deserialize_tagged<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+    where
+        T: UnitName,
+        Self::Repr: Deserialize<'de>,
+    {
+        let tagged = Tagged::<Self::Repr>::deserialize(deserializer)?;
+        let unit = T::unit_name();
+        if tagged.unit != unit {
+            return Err(D::Error::custom(format!(
+                "expected unit `{}`, found `{}`",
+                unit, tagged.unit
+            )));
+        }
+        Self::from_repr(tagged.data).map_err(D::Error::custom)
+    }
+}
+
+/// A number of `T`, in the unit of `T`.
+fn to_f64<T>(x: T) -> f64
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    x.value() / T::ONE.value()
+}
+
+fn from_f64<T>(x: f64) -> T
+where
+    T: Number,
+    T: Mul<f64, Output = T>,
+{
+    T::ONE * x
+}
+
+/// Implements `Serialize` and `Deserialize` through `Represent`.
+macro_rules! tagged {
+    ($type:ident) => {
+        impl<T> Serialize for $type<T>
+        where
+            T: Number,
+            T: HasValue<Output = f64>,
+            T: Mul<<T as HasValue>::Output, Output = T>,
+            T: UnitName,
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.serialize_tagged(serializer)
+            }
+        }
+
+        impl<'de, T> Deserialize<'de> for $type<T>
+        where
+            T: Number,
+            T: HasValue<Output = f64>,
+            T: Mul<<T as HasValue>::Output, Output = T>,
+            T: UnitName,
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Self::deserialize_tagged(deserializer)
+            }
+        }
+    };
+}
+
+//-------------------------------------------------- Point --------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+struct PointRepr {
+    x: f64,
+    y: f64,
+}
+
+impl<T> Represent<T> for Point<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr = PointRepr;
+
+    fn to_repr(&self) -> PointRepr {
+        PointRepr {
+            x: to_f64(self.x),
+            y: to_f64(self.y),
+        }
+    }
+
+    fn from_repr(repr: PointRepr) -> Result<Self, String> {
+        Ok(Point::new(from_f64(repr.x), from_f64(repr.y)))
+    }
+}
+
+tagged!(Point);
+
+//-------------------------------------------------- Vector --------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+struct VectorRepr {
+    dx: f64,
+    dy: f64,
+}
+
+impl<T> Represent<T> for Vector<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr = VectorRepr;
+
+    fn to_repr(&self) -> VectorRepr {
+        VectorRepr {
+            dx: to_f64(self.dx),
+            dy: to_f64(self.dy),
+        }
+    }
+
+    fn from_repr(repr: VectorRepr) -> Result<Self, String> {
+        Ok(Vector::new(from_f64(repr.dx), from_f64(repr.dy)))
+    }
+}
+
+tagged!(Vector);
+
+//-------------------------------------------------- Line --------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+struct LineRepr {
+    point: PointRepr,
+    vector: VectorRepr,
+}
+
+impl<T> Represent<T> for Line<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr = LineRepr;
+
+    fn to_repr(&self) -> LineRepr {
+        LineRepr {
+            point: self.point().to_repr(),
+            vector: self.vector().to_repr(),
+        }
+    }
+
+    fn from_repr(repr: LineRepr) -> Result<Self, String> {
+        Ok(Line::new(
+            Point::from_repr(repr.point)?,
+            Vector::from_repr(repr.vector)?,
+        ))
+    }
+}
+
+tagged!(Line);
+
+//-------------------------------------------------- Segment --------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+struct SegmentRepr {
+    first: PointRepr,
+    second: PointRepr,
+}
+
+impl<T> Represent<T> for Segment<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr = SegmentRepr;
+
+    fn to_repr(&self) -> SegmentRepr {
+        SegmentRepr {
+            first: self.first().to_repr(),
+            second: self.second().to_repr(),
+        }
+    }
+
+    fn from_repr(repr: SegmentRepr) -> Result<Self, String> {
+        Ok(Segment::new(
+            Point::from_repr(repr.first)?,
+            Point::from_repr(repr.second)?,
+        ))
+    }
+}
+
+tagged!(Segment);
+
+//-------------------------------------------------- Polygon --------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+struct PolygonRepr {
+    points: Vec<PointRepr>,
+}
+
+impl<T> Represent<T> for Polygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr = PolygonRepr;
+
+    fn to_repr(&self) -> PolygonRepr {
+        PolygonRepr {
+            points: self.points().iter().map(|p| p.to_repr()).collect(),
+        }
+    }
+
+    /// The vertices are checked as by [`Polygon::new`].
+    fn from_repr(repr: PolygonRepr) -> Result<Self, String> {
+        let points = repr
+            .points
+            .into_iter()
+            .map(Point::from_repr)
+            .collect::<Result<Vec<_>, _>>()?;
+        let values = points.iter().map(|p| p.to_value()).collect();
+        Polygon::new(values).map_err(|e| e.to_string())?;
+        Ok(Polygon::new_unchecked(points))
+    }
+}
+
+tagged!(Polygon);
+
+//-------------------------------------------------- Circle --------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+struct CircleRepr {
+    center: PointRepr,
+    radius: f64,
+}
+
+impl<T> Represent<T> for Circle<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr = CircleRepr;
+
+    fn to_repr(&self) -> CircleRepr {
+        CircleRepr {
+            center: self.center().to_repr(),
+            radius: to_f64(self.radius()),
+        }
+    }
+
+    fn from_repr(repr: CircleRepr) -> Result<Self, String> {
+        Ok(Circle::new(
+            Point::from_repr(repr.center)?,
+            from_f64(repr.radius),
+        ))
+    }
+}
+
+tagged!(Circle);
+
+//-------------------------------------------------- DirectedCircle --------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+struct DirectedCircleRepr {
+    center: PointRepr,
+    radius: f64,
+    direction: Direction,
+}
+
+impl<T> Represent<T> for DirectedCircle<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr = DirectedCircleRepr;
+
+    fn to_repr(&self) -> DirectedCircleRepr {
+        DirectedCircleRepr {
+            center: self.center().to_repr(),
+            radius: to_f64(self.radius()),
+            direction: self.direction(),
+        }
+    }
+
+    fn from_repr(repr: DirectedCircleRepr) -> Result<Self, String> {
+        let circle = Circle::new(Point::from_repr(repr.center)?, from_f64(repr.radius));
+        Ok(DirectedCircle::new(circle, repr.direction))
+    }
+}
+
+tagged!(DirectedCircle);
+
+//-------------------------------------------------- DirectedArc --------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+struct DirectedArcRepr {
+    #[serde(flatten)]
+    circle: DirectedCircleRepr,
+    start_angle: f64,
+    delta_angle: f64,
+}
+
+impl<T> Represent<T> for DirectedArc<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr = DirectedArcRepr;
+
+    fn to_repr(&self) -> DirectedArcRepr {
+        DirectedArcRepr {
+            circle: self.circle().to_repr(),
+            start_angle: self.start_angle().value(),
+            delta_angle: self.delta_angle().value(),
+        }
+    }
+
+    fn from_repr(repr: DirectedArcRepr) -> Result<Self, String> {
+        Ok(DirectedArc::new(
+            DirectedCircle::from_repr(repr.circle)?,
+            Radian::new(repr.start_angle),
+            Radian::new(repr.delta_angle),
+        ))
+    }
+}
+
+tagged!(DirectedArc);
+
+//-------------------------------------------------- Pose --------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+struct PoseRepr {
+    position: PointRepr,
+    orientation: VectorRepr,
+}
+
+impl<T> Represent<T> for Pose<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr = PoseRepr;
+
+    fn to_repr(&self) -> PoseRepr {
+        PoseRepr {
+            position: self.position().to_repr(),
+            orientation: self.orientation().to_repr(),
+        }
+    }
+
+    fn from_repr(repr: PoseRepr) -> Result<Self, String> {
+        Ok(Pose::new(
+            Point::from_repr(repr.position)?,
+            Vector::from_repr(repr.orientation)?,
+        ))
+    }
+}
+
+tagged!(Pose);
+
+//-------------------------------------------------- Trajectory --------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum TransitionRepr {
+    Segment(SegmentRepr),
+    Arc(DirectedArcRepr),
+}
+
+#[derive(Serialize, Deserialize)]
+struct TrajectoryRepr {
+    start: PoseRepr,
+    start_rotation: DirectedArcRepr,
+    transition: TransitionRepr,
+    finish_rotation: DirectedArcRepr,
+    finish: PoseRepr,
+}
+
+impl<T> Represent<T> for Trajectory<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr = TrajectoryRepr;
+
+    fn to_repr(&self) -> TrajectoryRepr {
+        TrajectoryRepr {
+            start: self.start().to_repr(),
+            start_rotation: self.start_rotation().to_repr(),
+            transition: match self.transition() {
+                Transition::Segment(seg) => TransitionRepr::Segment(seg.to_repr()),
+                Transition::Arc(arc) => TransitionRepr::Arc(arc.to_repr()),
+            },
+            finish_rotation: self.finish_rotation().to_repr(),
+            finish: self.finish().to_repr(),
+        }
+    }
+
+    fn from_repr(repr: TrajectoryRepr) -> Result<Self, String> {
+        let transition = match repr.transition {
+            TransitionRepr::Segment(seg) => Transition::Segment(Segment::from_repr(seg)?),
+            TransitionRepr::Arc(arc) => Transition::Arc(DirectedArc::from_repr(arc)?),
+        };
+        Ok(Trajectory::new(
+            Pose::from_repr(repr.start)?,
+            DirectedArc::from_repr(repr.start_rotation)?,
+            transition,
+            DirectedArc::from_repr(repr.finish_rotation)?,
+            Pose::from_repr(repr.finish)?,
+        ))
+    }
+}
+
+tagged!(Trajectory);
+
+//-------------------------------------------------- Path --------------------------------------------------
+
+#[derive(Serialize, Deserialize)]
+struct PathRepr {
+    trajectories: Vec<TrajectoryRepr>,
+}
+
+impl<T> Represent<T> for Path<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    type Repr = PathRepr;
+
+    fn to_repr(&self) -> PathRepr {
+        PathRepr {
+            trajectories: self.trajectories().iter().map(|t| t.to_repr()).collect(),
+        }
+    }
+
+    fn from_repr(repr: PathRepr) -> Result<Self, String> {
+        let trajectories = repr
+            .trajectories
+            .into_iter()
+            .map(Trajectory::from_repr)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Path::new(trajectories))
+    }
+}
+
+tagged!(Path);
@@ -0,0 +1,255 @@
+use super::*;
+
+use sity::*;
+
+/// Maximum number of items in a leaf of the hierarchy.
+const LEAF_SIZE: usize = 4;
+
+//-------------------------------------------------- SpatialIndex --------------------------------------------------
+
+/// A bounding volume hierarchy over shapes, built once from all of them.
+///
+/// Queries return the indices of the items, in the order they were given to [`SpatialIndex::new`].
+#[derive(Debug, Clone)]
+pub struct SpatialIndex<T, S>
+where
+    T: Number,
+{
+    items: Vec<S>,
+    boxes: Vec<Aabb<T>>,
+    nodes: Vec<Node<T>>,
+}
+
+#[derive(Debug, Clone)]
+enum Node<T: Number> {
+    Leaf(Aabb<T>, Vec<usize>),
+    Inner(Aabb<T>, usize, usize),
+}
+
+impl<T: Number> Node<T> {
+    fn bounds(&self) -> &Aabb<T> {
+        match self {
+            Node::Leaf(bounds, _) | Node::Inner(bounds, ..) => bounds,
+        }
+    }
+}
+
+impl<T, S> SpatialIndex<T, S>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    S: Bounded<T>,
+{
+    pub fn new(items: Vec<S>) -> Self {
+        let boxes = items.iter().map(|item| item.aabb()).collect::<Vec<_>>();
+        let mut index = Self {
+            items,
+            boxes,
+            nodes: vec![],
+        };
+        if !index.items.is_empty() {
+            let all = (0..index.items.len()).collect();
+            index.build(all);
+        }
+        index
+    }
+
+    /// Splits `items` at the median of their box centers, along the largest extent of these
+    /// centers, and returns the index of the created node.
+    fn build(&mut self, mut items: Vec<usize>) -> usize {
+        let bounds = items
+            .iter()
+            .map(|&i| self.boxes[i].clone())
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or_default();
+        if items.len() <= LEAF_SIZE {
+            self.nodes.push(Node::Leaf(bounds, items));
+            return self.nodes.len() - 1;
+        }
+
+        let center = |b: &Aabb<T>| {
+            [
+                (b.min().x.value() + b.max().x.value()) / 2.0,
+                (b.min().y.value() + b.max().y.value()) / 2.0,
+            ]
+        };
+        let centers = items
+            .iter()
+            .map(|&i| Point::new(center(&self.boxes[i])[0], center(&self.boxes[i])[1]))
+            .collect::<Vec<_>>();
+        let spread = Aabb::from_points(&centers).unwrap_or_default();
+        let axis = if spread.width() >= spread.height() {
+            0
+        } else {
+            1
+        };
+        items.sort_by(|&a, &b| {
+            center(&self.boxes[a])[axis].total_cmp(&center(&self.boxes[b])[axis])
+        });
+        let right = items.split_off(items.len() / 2);
+
+        // The node is pushed first, its children are set once built
+        self.nodes.push(Node::Leaf(bounds.clone(), vec![]));
+        let node = self.nodes.len() - 1;
+        let left = self.build(items);
+        let right = self.build(right);
+        self.nodes[node] = Node::Inner(bounds, left, right);
+        node
+    }
+
+    pub fn items(&self) -> &[S] {
+        &self.items
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the items sharing at least a point with `area`, as told by [`Bounded::overlaps`]:
+    /// exactly for segments, by their bounding box for the other shapes.
+    pub fn query_box(&self, area: &Aabb<T>) -> Vec<usize> {
+        let mut res = vec![];
+        self.visit(
+            |bounds| bounds.intersects(area),
+            |i| {
+                if self.boxes[i].intersects(area) && self.items[i].overlaps(area) {
+                    res.push(i);
+                }
+            },
+        );
+        res.sort_unstable();
+        res
+    }
+
+    /// Returns the items that may intersect `query`: the ones whose bounding box is overlapped
+    /// by `query`, see [`Bounded::overlaps`]. The exact intersection is left to the caller.
+    pub fn candidates<Q: Bounded<T>>(&self, query: &Q) -> Vec<usize> {
+        let mut res = vec![];
+        self.visit(
+            |bounds| query.overlaps(bounds),
+            |i| {
+                if query.overlaps(&self.boxes[i]) {
+                    res.push(i);
+                }
+            },
+        );
+        res.sort_unstable();
+        res
+    }
+
+    /// Calls `leaf` on the items of the leaves reached through the nodes accepted by `enter`.
+    fn visit<E, L>(&self, enter: E, mut leaf: L)
+    where
+        E: Fn(&Aabb<T>) -> bool,
+        L: FnMut(usize),
+    {
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if !enter(node.bounds()) {
+                continue;
+            }
+            match node {
+                Node::Leaf(_, items) => items.iter().for_each(|&i| leaf(i)),
+                Node::Inner(_, left, right) => stack.extend([*right, *left]),
+            }
+        }
+    }
+}
+
+impl<T, S> SpatialIndex<T, S>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    S: Bounded<T>,
+    Point<T>: Distance<T, S>,
+{
+    /// Returns the items closer than the radius to the center of `circle`, as measured by
+    /// [`Distance`] (for a polygon, the distance to its boundary).
+    pub fn query_circle(&self, circle: &Circle<T>) -> Vec<usize> {
+        let center = circle.center();
+        let r = circle.radius().value();
+        let mut res = vec![];
+        self.visit(
+            |bounds| distance_to_box(center, bounds) <= r,
+            |i| {
+                if distance_to_box(center, &self.boxes[i]) <= r
+                    && center.distance(&self.items[i]).value() <= r
+                {
+                    res.push(i);
+                }
+            },
+        );
+        res.sort_unstable();
+        res
+    }
+
+    /// Returns the item closest to `point` with its distance, `None` if the index is empty.
+    ///
+    /// Boxes give a lower bound on the distance to their items, the exact distance is computed
+    /// with [`Distance`] only for the items that may beat the closest one found so far.
+    pub fn nearest(&self, point: &Point<T>) -> Option<(usize, T)> {
+        let mut best: Option<(usize, T)> = None;
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+        while let Some(node) = stack.pop() {
+            let bound = |b: &Aabb<T>| distance_to_box(point, b);
+            let beaten = |d: f64, best: &Option<(usize, T)>| {
+                best.as_ref().is_none_or(|(_, b)| d < b.value())
+            };
+            let node = &self.nodes[node];
+            if !beaten(bound(node.bounds()), &best) {
+                continue;
+            }
+            match node {
+                Node::Leaf(_, items) => {
+                    for &i in items {
+                        if !beaten(bound(&self.boxes[i]), &best) {
+                            continue;
+                        }
+                        let d = point.distance(&self.items[i]);
+                        if beaten(d.value(), &best) {
+                            best = Some((i, d));
+                        }
+                    }
+                }
+                Node::Inner(_, left, right) => {
+                    // The closest child is visited first
+                    let (dl, dr) = (
+                        bound(self.nodes[*left].bounds()),
+                        bound(self.nodes[*right].bounds()),
+                    );
+                    if dl <= dr {
+                        stack.extend([*right, *left]);
+                    } else {
+                        stack.extend([*left, *right]);
+                    }
+                }
+            }
+        }
+        best
+    }
+}
+
+/// Distance from `p` to the closest point of `b`, zero inside.
+fn distance_to_box<T>(p: &Point<T>, b: &Aabb<T>) -> f64
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    let (x, y) = (p.x.value(), p.y.value());
+    let dx = (b.min().x.value() - x).max(x - b.max().x.value()).max(0.0);
+    let dy = (b.min().y.value() - y).max(y - b.max().y.value()).max(0.0);
+    dx.hypot(dy)
+}
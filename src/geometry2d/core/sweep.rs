@@ -0,0 +1,145 @@
+use super::*;
+use std::f64::consts::PI;
+use std::ops::{Div, DivAssign, Mul};
+
+use sity::*;
+
+/// A mapping path along with the sweep angle it was built for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepResult<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    /// The angle of the passes from the x axis, in `[0, π)`.
+    pub angle: Radian<<T as HasValue>::Output>,
+    /// The length of `path`.
+    pub length: T,
+    pub path: Path<T>,
+}
+
+impl<T> SweepResult<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    pub fn new(angle: Radian<<T as HasValue>::Output>, length: T, path: Path<T>) -> Self {
+        Self {
+            angle,
+            length,
+            path,
+        }
+    }
+}
+
+//-------------------------------------------------- Sweep --------------------------------------------------
+
+impl<T> Path<T>
+where
+    T: Number + AngleFactory,
+    T: HasValue<Output = f64>,
+    //
+    <T as HasValue>::Output: AngleOps,
+    <T as HasValue>::Output: FromValue<usize>,
+    <T as HasValue>::Output: Mul<T, Output = T>,
+    <T as HasValue>::Output: Mul<<T as Pow2>::Output, Output = <T as Pow2>::Output>,
+    //
+    T: std::ops::Neg<Output = T>,
+    //
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul<T, Output = <T as Pow2>::Output>,
+    T: Mul<<<T as Pow2>::Output as Div>::Output, Output = T>,
+    T: Mul<<T as Pow2>::Output, Output = <T as Pow3>::Output>,
+    //
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: DivAssign<<T as HasValue>::Output>,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number + AngleFactory,
+    <T as Pow2>::Output: Div,
+    <T as Pow2>::Output: Root2<Output = T>,
+    <T as Pow2>::Output: HasValue<Output = <T as HasValue>::Output>,
+    <T as Pow2>::Output: Mul<T, Output = <T as Pow3>::Output>,
+    <<T as Pow2>::Output as Div>::Output: Number,
+    //
+    T: Pow3,
+    <T as Pow3>::Output: Div<<T as Pow2>::Output, Output = T>,
+    <T as Pow3>::Output: Mul<T, Output = <T as Pow4>::Output>,
+    //
+    T: Pow4,
+    <T as Pow4>::Output: Root2<Output = <T as Pow2>::Output>,
+{
+    /// Searches the sweep angle giving the shortest mapping path: the angles `resolution` apart
+    /// in `[0, π)` are tried first, then the best one is refined `refinements` times by trying
+    /// its neighbours at half the previous step. Returns `None` when `resolution` is not positive
+    /// or when no angle gives a path.
+    pub fn sweep_angle_mapping<P: Mapping<T>>(
+        start: &Pose<T>,
+        radius: T,
+        distance: T,
+        polygon: &P,
+        resolution: Radian<<T as HasValue>::Output>,
+        refinements: usize,
+    ) -> Option<SweepResult<T>> {
+        let resolution = resolution.value();
+        if resolution.is_nan() || resolution <= 0.0 {
+            return None;
+        }
+        let count = (PI / resolution).ceil().max(1.0) as usize;
+        let mut step = PI / count as f64;
+
+        let mut best: Option<SweepResult<T>> = None;
+        let keep = |candidate: Option<SweepResult<T>>, best: &mut Option<SweepResult<T>>| {
+            if let Some(candidate) = candidate.filter(|c| c.length.value().is_finite()) {
+                if best.as_ref().is_none_or(|b| candidate.length < b.length) {
+                    *best = Some(candidate);
+                }
+            }
+        };
+        for k in 0..count {
+            let candidate = Self::sweep_at(start, radius, distance, polygon, k as f64 * step);
+            keep(candidate, &mut best);
+        }
+
+        for _ in 0..refinements {
+            let Some(angle) = best.as_ref().map(|b| b.angle.value()) else {
+                break;
+            };
+            step /= 2.0;
+            for angle in [angle - step, angle + step] {
+                let candidate = Self::sweep_at(start, radius, distance, polygon, angle);
+                keep(candidate, &mut best);
+            }
+        }
+        best
+    }
+
+    /// Maps `polygon` with passes parallel to its minimum width direction, found by rotating
+    /// calipers on the convex hull: this minimizes the number of passes, in closed form. Returns
+    /// `None` when the surface gives no [`Mapping::mapping_vertices`].
+    pub fn min_width_mapping<P: Mapping<T>>(
+        start: &Pose<T>,
+        radius: T,
+        distance: T,
+        polygon: &P,
+    ) -> Option<SweepResult<T>> {
+        let (_, direction) = Polygon::convex_hull(polygon.mapping_vertices())?.min_width()?;
+        let angle = direction.dy.value().atan2(direction.dx.value());
+        Self::sweep_at(start, radius, distance, polygon, angle)
+    }
+
+    /// Maps `polygon` with passes at `angle` from the x axis.
+    fn sweep_at<P: Mapping<T>>(
+        start: &Pose<T>,
+        radius: T,
+        distance: T,
+        polygon: &P,
+        angle: f64,
+    ) -> Option<SweepResult<T>> {
+        let angle = angle.rem_euclid(PI);
+        let direction = Vector::new(T::ONE * angle.cos(), T::ONE * angle.sin());
+        let path = Self::mapping(start, &direction, radius, distance, polygon)?;
+        let length = path.length();
+        Some(SweepResult::new(Radian::new(angle), length, path))
+    }
+}
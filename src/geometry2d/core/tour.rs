@@ -0,0 +1,271 @@
+use super::*;
+use std::f64::consts::PI;
+use std::ops::{Div, DivAssign, Mul};
+
+use sity::*;
+
+/// A path visiting a set of points, along with the order in which they are visited.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TourResult<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    /// The indices of the points, in visiting order.
+    pub order: Vec<usize>,
+    pub path: Path<T>,
+}
+
+impl<T> TourResult<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    pub fn new(order: Vec<usize>, path: Path<T>) -> Self {
+        Self { order, path }
+    }
+}
+
+/// Maximum number of improvement rounds of the local search.
+const MAX_ROUNDS: usize = 32;
+
+//-------------------------------------------------- Tour --------------------------------------------------
+
+impl<T> Path<T>
+where
+    T: Number + AngleFactory,
+    T: HasValue<Output = f64>,
+    //
+    <T as HasValue>::Output: AngleOps,
+    <T as HasValue>::Output: FromValue<usize>,
+    <T as HasValue>::Output: Mul<T, Output = T>,
+    <T as HasValue>::Output: Mul<<T as Pow2>::Output, Output = <T as Pow2>::Output>,
+    //
+    T: std::ops::Neg<Output = T>,
+    //
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul<T, Output = <T as Pow2>::Output>,
+    T: Mul<<<T as Pow2>::Output as Div>::Output, Output = T>,
+    T: Mul<<T as Pow2>::Output, Output = <T as Pow3>::Output>,
+    //
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: DivAssign<<T as HasValue>::Output>,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number + AngleFactory,
+    <T as Pow2>::Output: Div,
+    <T as Pow2>::Output: Root2<Output = T>,
+    <T as Pow2>::Output: HasValue<Output = <T as HasValue>::Output>,
+    <T as Pow2>::Output: Mul<T, Output = <T as Pow3>::Output>,
+    <<T as Pow2>::Output as Div>::Output: Number,
+    //
+    T: Pow3,
+    <T as Pow3>::Output: Div<<T as Pow2>::Output, Output = T>,
+    <T as Pow3>::Output: Mul<T, Output = <T as Pow4>::Output>,
+    //
+    T: Pow4,
+    <T as Pow4>::Output: Root2<Output = <T as Pow2>::Output>,
+{
+    /// Visits every point of `points` from `start`, choosing both the order and the heading at
+    /// each point (Dubins traveling salesman).
+    ///
+    /// The order is the nearest neighbour tour improved by 2-opt on straight-line distances, and
+    /// the headings are first set by the alternating algorithm (along every other leg), then
+    /// improved point by point among `headings` equally spaced directions, using the
+    /// [`Trajectory::create`] lengths. Finally, 2-opt moves are kept when they shorten the path.
+    ///
+    /// Each improvement round, until none improves or a fixed maximum, builds O(n × headings)
+    /// trajectories for the headings and O(n³) for the 2-opt moves, with n the number of points:
+    /// the search suits tours of a few dozen points.
+    pub fn tour(
+        start: &Pose<T>,
+        points: &[Point<T>],
+        radius: T,
+        headings: usize,
+    ) -> Option<TourResult<T>> {
+        if points.is_empty() {
+            return None;
+        }
+        let samples = (0..headings.max(1))
+            .map(|k| k as f64 * 2.0 * PI / headings.max(1) as f64)
+            .collect::<Vec<_>>();
+
+        let mut order = Self::euclidean_order(start.position(), points);
+        let mut angles = Self::alternating_headings(start.position(), points, &order);
+        let cost = |order: &[usize], angles: &[f64]| {
+            Self::tour_poses(start, points, order, angles)
+                .windows(2)
+                .map(|w| Self::leg(&w[0], &w[1], radius))
+                .sum::<f64>()
+        };
+
+        for _ in 0..MAX_ROUNDS {
+            let mut improved = false;
+
+            // Heading of each point, given its neighbours
+            for i in 0..order.len() {
+                let previous = if i == 0 {
+                    start.clone()
+                } else {
+                    Self::tour_pose(points, order[i - 1], angles[i - 1])
+                };
+                let next = (i + 1 < order.len())
+                    .then(|| Self::tour_pose(points, order[i + 1], angles[i + 1]));
+                let local = |angle: f64| {
+                    let pose = Self::tour_pose(points, order[i], angle);
+                    Self::leg(&previous, &pose, radius)
+                        + next.as_ref().map_or(0.0, |n| Self::leg(&pose, n, radius))
+                };
+                let mut best = (local(angles[i]), angles[i]);
+                for &angle in samples.iter() {
+                    let l = local(angle);
+                    if l < best.0 - 1e-9 {
+                        best = (l, angle);
+                    }
+                }
+                if best.1 != angles[i] {
+                    angles[i] = best.1;
+                    improved = true;
+                }
+            }
+            let mut length = cost(&order, &angles);
+
+            // 2-opt: reversing a run of points also reverses their headings
+            for i in 0..order.len() {
+                for j in i + 1..order.len() {
+                    let mut o = order.clone();
+                    let mut a = angles.clone();
+                    o[i..=j].reverse();
+                    a[i..=j].reverse();
+                    a[i..=j]
+                        .iter_mut()
+                        .for_each(|h| *h = (*h + PI) % (2.0 * PI));
+                    let l = cost(&o, &a);
+                    if l < length - 1e-9 {
+                        (order, angles, length) = (o, a, l);
+                        improved = true;
+                    }
+                }
+            }
+
+            if !improved {
+                break;
+            }
+        }
+
+        let poses = Self::tour_poses(start, points, &order, &angles);
+        let path = Self::create(radius, &poses)?;
+        Some(TourResult::new(order, path))
+    }
+
+    /// Length of the shortest Dubins trajectory between two poses.
+    fn leg(from: &Pose<T>, to: &Pose<T>, radius: T) -> f64 {
+        Trajectory::create(from, to, radius)
+            .map(|traj| traj.length().value())
+            .filter(|l| l.is_finite())
+            .unwrap_or(f64::INFINITY)
+    }
+
+    fn tour_pose(points: &[Point<T>], index: usize, angle: f64) -> Pose<T> {
+        Pose::new(
+            points[index].clone(),
+            Vector::new(T::ONE * angle.cos(), T::ONE * angle.sin()),
+        )
+    }
+
+    fn tour_poses(
+        start: &Pose<T>,
+        points: &[Point<T>],
+        order: &[usize],
+        angles: &[f64],
+    ) -> Vec<Pose<T>> {
+        std::iter::once(start.clone())
+            .chain(
+                order
+                    .iter()
+                    .zip(angles.iter())
+                    .map(|(&i, &angle)| Self::tour_pose(points, i, angle)),
+            )
+            .collect()
+    }
+
+    /// Nearest neighbour order from `start`, improved by 2-opt on straight-line distances.
+    fn euclidean_order(start: &Point<T>, points: &[Point<T>]) -> Vec<usize> {
+        let mut remaining = (0..points.len()).collect::<Vec<_>>();
+        let mut order = vec![];
+        let mut position = start.clone();
+        while !remaining.is_empty() {
+            let (k, _) = remaining
+                .iter()
+                .enumerate()
+                .map(|(k, &i)| (k, position.distance(&points[i]).value()))
+                .fold(
+                    (0, f64::INFINITY),
+                    |best, c| if c.1 < best.1 { c } else { best },
+                );
+            let i = remaining.remove(k);
+            position = points[i].clone();
+            order.push(i);
+        }
+
+        let at = |order: &[usize], k: usize| {
+            if k == 0 {
+                start.clone()
+            } else {
+                points[order[k - 1]].clone()
+            }
+        };
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 1..order.len() {
+                for j in i + 1..=order.len() {
+                    // Reverse the points i - 1 .. j - 1 (open tour: no edge after the last point)
+                    let (a, b) = (at(&order, i - 1), at(&order, i));
+                    let c = at(&order, j);
+                    let before = a.distance(&b).value()
+                        + if j < order.len() {
+                            c.distance(&points[order[j]]).value()
+                        } else {
+                            0.0
+                        };
+                    let after = a.distance(&c).value()
+                        + if j < order.len() {
+                            b.distance(&points[order[j]]).value()
+                        } else {
+                            0.0
+                        };
+                    if after < before - 1e-9 {
+                        order[i - 1..j].reverse();
+                        improved = true;
+                    }
+                }
+            }
+        }
+        order
+    }
+
+    /// Alternating algorithm: the odd legs are driven straight, so both of their ends are
+    /// headed along them; the last point of an even count keeps the heading of its leg.
+    fn alternating_headings(start: &Point<T>, points: &[Point<T>], order: &[usize]) -> Vec<f64> {
+        let heading = |a: &Point<T>, b: &Point<T>| {
+            (b.y.value() - a.y.value()).atan2(b.x.value() - a.x.value())
+        };
+        let mut angles = vec![0.0; order.len()];
+        for k in (0..order.len()).step_by(2) {
+            let a = &points[order[k]];
+            let h = if k + 1 < order.len() {
+                heading(a, &points[order[k + 1]])
+            } else if k > 0 {
+                heading(&points[order[k - 1]], a)
+            } else {
+                heading(start, a)
+            };
+            angles[k] = h.rem_euclid(2.0 * PI);
+            if k + 1 < order.len() {
+                angles[k + 1] = h.rem_euclid(2.0 * PI);
+            }
+        }
+        angles
+    }
+}
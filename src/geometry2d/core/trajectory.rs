@@ -4,10 +4,121 @@ use std::ops::{Div, DivAssign, Mul};
 
 use sity::*;
 
-/// Represents a 2D trajectory consisting of two arcs (start and finish rotations)
-/// connected by a straight segment. The trajectory starts at `start` pose,
-/// follows an arc (`start_rotation`), then a straight segment (`segment`),
-/// then another arc (`finish_rotation`), and ends at `finish` pose.
+//-------------------------------------------------- Transition --------------------------------------------------
+
+/// The element joining the start and finish rotations of a trajectory.
+///
+/// It is either a straight segment (CSC words: LSL, RSR, LSR, RSL) or an arc
+/// turning in the opposite direction of the two rotations (CCC words: LRL, RLR).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Transition<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    /// A straight segment tangent to both rotations, empty when they lie on the same circle.
+    Segment(Segment<T>),
+    /// An arc tangent to both rotations.
+    Arc(DirectedArc<T>),
+}
+
+impl<T> Default for Transition<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    fn default() -> Self {
+        Self::Segment(Segment::default())
+    }
+}
+
+impl<T> From<Segment<T>> for Transition<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    fn from(value: Segment<T>) -> Self {
+        Self::Segment(value)
+    }
+}
+
+impl<T> From<DirectedArc<T>> for Transition<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    fn from(value: DirectedArc<T>) -> Self {
+        Self::Arc(value)
+    }
+}
+
+impl<T> Transition<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    pub fn to_value(&self) -> Transition<<T as HasValue>::Output> {
+        match self {
+            Transition::Segment(seg) => Transition::Segment(seg.to_value()),
+            Transition::Arc(arc) => Transition::Arc(arc.to_value()),
+        }
+    }
+}
+
+impl<T> Transition<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    // DirectecArc
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    // Segment
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+{
+    pub fn length(&self) -> T {
+        match self {
+            Transition::Segment(seg) => seg.length(),
+            Transition::Arc(arc) => arc.length(),
+        }
+    }
+}
+
+impl<T> Transform<T> for Transition<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        match self {
+            Transition::Segment(seg) => Transition::Segment(seg.transformed(transform)),
+            Transition::Arc(arc) => Transition::Arc(arc.transformed(transform)),
+        }
+    }
+}
+
+impl<T> Display for Transition<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Transition::Segment(seg) => write!(f, "{}", seg),
+            Transition::Arc(arc) => write!(f, "{}", arc),
+        }
+    }
+}
+
+//-------------------------------------------------- Trajectory --------------------------------------------------
+
+/// Represents a 2D Dubins trajectory consisting of two arcs (start and finish rotations)
+/// connected by a transition. The trajectory starts at `start` pose,
+/// follows an arc (`start_rotation`), then a transition (`transition`) which is either a
+/// straight segment or an arc turning the other way, then another arc (`finish_rotation`),
+/// and ends at `finish` pose.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Trajectory<T>
 where
@@ -18,8 +129,8 @@ where
     start: Pose<T>,
     /// The arc representing the initial rotation from the start pose.
     start_rotation: DirectedArc<T>,
-    /// The straight segment connecting the two arcs.
-    segment: Segment<T>,
+    /// The segment or arc connecting the two rotations.
+    transition: Transition<T>,
     /// The arc representing the final rotation to the finish pose.
     finish_rotation: DirectedArc<T>,
     /// The ending pose of the trajectory.
@@ -36,14 +147,14 @@ where
     pub fn new(
         start: Pose<T>,
         start_rotation: DirectedArc<T>,
-        segment: Segment<T>,
+        transition: Transition<T>,
         finish_rotation: DirectedArc<T>,
         finish: Pose<T>,
     ) -> Self {
         Self {
             start,
             start_rotation,
-            segment,
+            transition,
             finish_rotation,
             finish,
         }
@@ -57,8 +168,24 @@ where
         &self.start_rotation
     }
 
-    pub fn segment(&self) -> &Segment<T> {
-        &self.segment
+    pub fn transition(&self) -> &Transition<T> {
+        &self.transition
+    }
+
+    /// Returns the straight segment of a CSC trajectory, `None` for a CCC trajectory.
+    pub fn segment(&self) -> Option<&Segment<T>> {
+        match &self.transition {
+            Transition::Segment(seg) => Some(seg),
+            Transition::Arc(_) => None,
+        }
+    }
+
+    /// Returns the middle arc of a CCC trajectory, `None` for a CSC trajectory.
+    pub fn middle_rotation(&self) -> Option<&DirectedArc<T>> {
+        match &self.transition {
+            Transition::Segment(_) => None,
+            Transition::Arc(arc) => Some(arc),
+        }
     }
 
     pub fn finish_rotation(&self) -> &DirectedArc<T> {
@@ -84,7 +211,7 @@ where
     <T as Pow2>::Output: Root2<Output = T>,
 {
     pub fn length(&self) -> T {
-        self.start_rotation.length() + self.segment.length() + self.finish_rotation.length()
+        self.start_rotation.length() + self.transition.length() + self.finish_rotation.length()
     }
 }
 
@@ -159,104 +286,106 @@ where
         };
         //
         let mut res = vec![];
-        // Clock -> Clock
-        if let Some(seg) =
-            start_clock.tangents_to_circle(finish_clock.center(), finish_clock.direction())
-        {
-            // Start
-            let start_angle = start_clock.center().angle_from_point(start.position());
-            let mut delta_angle =
-                Point::angle_from_points(seg.first(), start_clock.center(), start.position());
-            if delta_angle < Radian::ZERO {
-                delta_angle += Radian::PI + Radian::PI;
+        // Circle -> Segment -> Circle
+        for (first, second) in [
+            (&start_clock, &finish_clock),
+            (&start_counter, &finish_counter),
+            (&start_clock, &finish_counter),
+            (&start_counter, &finish_clock),
+        ] {
+            // Same circle, up to rounding: a single arc, the tangent collapses to the finish
+            // position
+            let scale = radius.abs()
+                + first.center().x.abs()
+                + first.center().y.abs()
+                + second.center().x.abs()
+                + second.center().y.abs();
+            if first.direction() == second.direction()
+                && first.center().distance(second.center())
+                    <= scale * <T as HasValue>::Output::EPSILON
+            {
+                res.push(Trajectory::new(
+                    start.clone(),
+                    first.arc_between(start.position(), finish.position()),
+                    Transition::Segment(Segment::new(
+                        finish.position().clone(),
+                        finish.position().clone(),
+                    )),
+                    second.arc_between(finish.position(), finish.position()),
+                    finish.clone(),
+                ));
+                continue;
             }
-            let start_arc = DirectedArc::new(start_clock.clone(), start_angle, delta_angle);
-            // Finish
-            let finish_angle = finish_clock.center().angle_from_point(seg.second());
-            let mut delta_angle =
-                Point::angle_from_points(finish.position(), finish_clock.center(), seg.second());
-            if delta_angle < Radian::ZERO {
-                delta_angle += Radian::PI + Radian::PI;
+            if let Some(seg) = first.tangents_to_circle(second.center(), second.direction()) {
+                let start_arc = first.arc_between(start.position(), seg.first());
+                let finish_arc = second.arc_between(seg.second(), finish.position());
+                let traj = Trajectory::new(
+                    start.clone(),
+                    start_arc,
+                    Transition::Segment(seg),
+                    finish_arc,
+                    finish.clone(),
+                );
+                res.push(traj);
             }
-            let finish_arc = DirectedArc::new(finish_clock.clone(), finish_angle, delta_angle);
-            //
-            let traj = Trajectory::new(start.clone(), start_arc, seg, finish_arc, finish.clone());
-            res.push(traj);
         }
-        // Counter -> Counter
-        if let Some(seg) =
-            start_counter.tangents_to_circle(finish_counter.center(), finish_counter.direction())
-        {
-            // Start
-            let start_angle = start_counter.center().angle_from_point(start.position());
-            let mut delta_angle =
-                Point::angle_from_points(start.position(), start_counter.center(), seg.first());
-            if delta_angle < Radian::ZERO {
-                delta_angle += Radian::PI + Radian::PI;
-            }
-            let start_arc = DirectedArc::new(start_counter.clone(), start_angle, delta_angle);
-            // Finish
-            let finish_angle = finish_counter.center().angle_from_point(seg.second());
-            let mut delta_angle =
-                Point::angle_from_points(seg.second(), finish_counter.center(), finish.position());
-            if delta_angle < Radian::ZERO {
-                delta_angle += Radian::PI + Radian::PI;
-            }
-            let finish_arc = DirectedArc::new(finish_counter.clone(), finish_angle, delta_angle);
-            //
-            let traj = Trajectory::new(start.clone(), start_arc, seg, finish_arc, finish.clone());
-            res.push(traj);
+        // Circle -> Circle -> Circle
+        for (first, second) in [
+            (&start_clock, &finish_clock),
+            (&start_counter, &finish_counter),
+        ] {
+            res.extend(Self::create_ccc(start, first, second, finish));
         }
-        // Clock -> Counter
-        if let Some(seg) =
-            start_clock.tangents_to_circle(finish_counter.center(), finish_counter.direction())
-        {
-            // Start
-            let start_angle = start_clock.center().angle_from_point(start.position());
-            let mut delta_angle =
-                Point::angle_from_points(seg.first(), start_clock.center(), start.position());
-            if delta_angle < Radian::ZERO {
-                delta_angle += Radian::PI + Radian::PI;
-            }
-            let start_arc = DirectedArc::new(start_clock.clone(), start_angle, delta_angle);
-            // Finish
-            let finish_angle = finish_counter.center().angle_from_point(seg.second());
-            let mut delta_angle =
-                Point::angle_from_points(seg.second(), finish_counter.center(), finish.position());
-            if delta_angle < Radian::ZERO {
-                delta_angle += Radian::PI + Radian::PI;
-            }
-            let finish_arc = DirectedArc::new(finish_counter.clone(), finish_angle, delta_angle);
-            //
-            let traj = Trajectory::new(start.clone(), start_arc, seg, finish_arc, finish.clone());
-            res.push(traj);
+
+        //
+        res
+    }
+
+    /// Builds the CCC trajectories (LRL or RLR) joining `first` and `second` through a third
+    /// circle of the same radius, tangent to both and turning in the opposite direction.
+    /// There are up to two such middle circles, one on each side of the line between centers.
+    fn create_ccc(
+        start: &Pose<T>,
+        first: &DirectedCircle<T>,
+        second: &DirectedCircle<T>,
+        finish: &Pose<T>,
+    ) -> Vec<Self> {
+        let radius = first.radius();
+        let diameter = radius + radius;
+        let d = first.center().distance(second.center());
+        if d <= T::EPSILON || d >= diameter + diameter {
+            return vec![];
         }
-        // Counter -> Clock
-        if let Some(seg) =
-            start_counter.tangents_to_circle(finish_clock.center(), finish_clock.direction())
-        {
-            // Start
-            let start_angle = start_counter.center().angle_from_point(start.position());
-            let mut delta_angle =
-                Point::angle_from_points(start.position(), start_counter.center(), seg.first());
-            if delta_angle < Radian::ZERO {
-                delta_angle += Radian::PI + Radian::PI;
-            }
-            let start_arc = DirectedArc::new(start_counter.clone(), start_angle, delta_angle);
-            // Finish
-            let finish_angle = finish_clock.center().angle_from_point(seg.second());
-            let mut delta_angle =
-                Point::angle_from_points(finish.position(), finish_clock.center(), seg.second());
-            if delta_angle < Radian::ZERO {
-                delta_angle += Radian::PI + Radian::PI;
-            }
-            let finish_arc = DirectedArc::new(finish_clock.clone(), finish_angle, delta_angle);
+        let two = (T::ONE + T::ONE).value();
+        let v: Vector<T> = (first.center(), second.center()).into();
+        let middle = first.center() + &v / two;
+        let h = (diameter.pow2() - (d / two).pow2()).root2();
+        let direction = first.direction().reversed();
+
+        let mut res = vec![];
+        for side in [
+            v.perpendicular_clockwise(),
+            v.perpendicular_counterclockwise(),
+        ] {
+            let center = &middle + side.scale(h);
+            let circle = DirectedCircle::new(Circle::new(center.clone(), radius), direction);
+            let to_first: Vector<T> = (first.center(), &center).into();
+            let t1 = first.center() + to_first / two;
+            let to_second: Vector<T> = (second.center(), &center).into();
+            let t2 = second.center() + to_second / two;
             //
-            let traj = Trajectory::new(start.clone(), start_arc, seg, finish_arc, finish.clone());
+            let start_arc = first.arc_between(start.position(), &t1);
+            let middle_arc = circle.arc_between(&t1, &t2);
+            let finish_arc = second.arc_between(&t2, finish.position());
+            let traj = Trajectory::new(
+                start.clone(),
+                start_arc,
+                Transition::Arc(middle_arc),
+                finish_arc,
+                finish.clone(),
+            );
             res.push(traj);
         }
-
-        //
         res
     }
 
@@ -276,6 +405,117 @@ where
     }
 }
 
+//-------------------------------------------------- To Point --------------------------------------------------
+
+impl<T> Trajectory<T>
+where
+    T: Number + AngleFactory,
+    T: HasValue<Output = f64>,
+    //
+    <T as HasValue>::Output: AngleOps,
+    <T as HasValue>::Output: Mul<T, Output = T>,
+    <T as HasValue>::Output: Mul<<T as Pow2>::Output, Output = <T as Pow2>::Output>,
+    //
+    T: std::ops::Neg<Output = T>,
+    //
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul<T, Output = <T as Pow2>::Output>,
+    T: Mul<<T as Pow2>::Output, Output = <T as Pow3>::Output>,
+    //
+    T: Div<<T as HasValue>::Output, Output = T>,
+    T: DivAssign<<T as HasValue>::Output>,
+    //
+    T: Pow2,
+    <T as Pow2>::Output: Number,
+    <T as Pow2>::Output: Root2<Output = T>,
+    <T as Pow2>::Output: Number + AngleFactory,
+    <T as Pow2>::Output: HasValue<Output = <T as HasValue>::Output>,
+    <T as Pow2>::Output: Mul<T, Output = <T as Pow3>::Output>,
+    //
+    T: Pow3,
+    <T as Pow3>::Output: Div<<T as Pow2>::Output, Output = T>,
+    //
+    T: Pow4,
+    <T as Pow3>::Output: Mul<T, Output = <T as Pow4>::Output>,
+    <T as Pow4>::Output: Root2<Output = <T as Pow2>::Output>,
+{
+    /// Returns the trajectories from `start` to `point`, reached at any heading: a rotation then
+    /// a segment (CS), or a rotation then a rotation the other way (CC) when the point is too
+    /// close to turn towards it. The finish rotation is empty and the finish pose holds the
+    /// arrival heading, so the result can be followed and drawn like any other trajectory.
+    pub fn create_all_to_point(start: &Pose<T>, point: &Point<T>, radius: T) -> Vec<Self> {
+        let two = (T::ONE + T::ONE).value();
+        let mut res = vec![];
+        for direction in [Direction::ClockWise, Direction::CounterClockWise] {
+            let first = Self::turning_circle(start, radius, direction);
+
+            // Circle -> Segment
+            if let Some(tangent) = first.tangent_to_point(point) {
+                let start_arc = first.arc_between(start.position(), tangent.first());
+                let heading = first.tangent(start_arc.finish_angle());
+                let finish = Pose::new(point.clone(), heading);
+                let finish_arc =
+                    Self::turning_circle(&finish, radius, direction).arc_between(point, point);
+                res.push(Trajectory::new(
+                    start.clone(),
+                    start_arc,
+                    Transition::Segment(tangent),
+                    finish_arc,
+                    finish,
+                ));
+            }
+
+            // Circle -> Circle: the second circle is tangent to the first one and goes through the point
+            let centers = Circle::new(first.center().clone(), radius + radius)
+                .intersection(&Circle::new(point.clone(), radius));
+            let centers = match centers {
+                CircleIntersection::Two(c1, c2) => vec![c1, c2],
+                CircleIntersection::Tangent(c) | CircleIntersection::One(c) => vec![c],
+                _ => vec![],
+            };
+            for center in centers {
+                let to_center: Vector<T> = (first.center(), &center).into();
+                let contact = first.center() + to_center / two;
+                let second = DirectedCircle::new(Circle::new(center, radius), direction.reversed());
+                let start_arc = first.arc_between(start.position(), &contact);
+                let middle_arc = second.arc_between(&contact, point);
+                let finish_arc = second.arc_between(point, point);
+                let heading = second.tangent(finish_arc.start_angle());
+                res.push(Trajectory::new(
+                    start.clone(),
+                    start_arc,
+                    Transition::Arc(middle_arc),
+                    finish_arc,
+                    Pose::new(point.clone(), heading),
+                ));
+            }
+        }
+        res
+    }
+
+    /// Returns the shortest trajectory from `start` to `point`, reached at any heading.
+    pub fn create_to_point(start: &Pose<T>, point: &Point<T>, radius: T) -> Option<Self> {
+        Self::create_all_to_point(start, point, radius)
+            .into_iter()
+            .filter(|traj| traj.length().value().is_finite())
+            .min_by(|a, b| {
+                a.length()
+                    .partial_cmp(&b.length())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+    }
+
+    /// Returns the circle of radius `radius` tangent to `pose`, turning in `direction`.
+    fn turning_circle(pose: &Pose<T>, radius: T, direction: Direction) -> DirectedCircle<T> {
+        let v = match direction {
+            Direction::ClockWise => pose.orientation().perpendicular_clockwise(),
+            Direction::CounterClockWise => pose.orientation().perpendicular_counterclockwise(),
+        };
+        let p = pose.position() + v.scale(radius);
+        DirectedCircle::new(Circle::new(p, radius), direction)
+    }
+}
+
 //-------------------------------------------------- ToValue --------------------------------------------------
 
 impl<T> Trajectory<T>
@@ -287,7 +527,7 @@ where
         Trajectory {
             start: self.start.to_value(),
             start_rotation: self.start_rotation.to_value(),
-            segment: self.segment.to_value(),
+            transition: self.transition.to_value(),
             finish_rotation: self.finish_rotation.to_value(),
             finish: self.finish.to_value(),
         }
@@ -298,6 +538,26 @@ where
 
 // TODO
 
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for Trajectory<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        Self {
+            start: self.start.transformed(transform),
+            start_rotation: self.start_rotation.transformed(transform),
+            transition: self.transition.transformed(transform),
+            finish_rotation: self.finish_rotation.transformed(transform),
+            finish: self.finish.transformed(transform),
+        }
+    }
+}
+
 //-------------------------------------------------- Ops --------------------------------------------------
 
 // TODO
@@ -313,7 +573,7 @@ where
         write!(
             f,
             "Trajectory({}, {}, {}, {}, {})",
-            self.start, self.start_rotation, self.segment, self.finish_rotation, self.finish
+            self.start, self.start_rotation, self.transition, self.finish_rotation, self.finish
         )
     }
 }
@@ -0,0 +1,229 @@
+use super::*;
+use sity::*;
+use std::fmt::Display;
+use std::ops::{Mul, Neg};
+
+/// A 2D rigid-body transform: an optional mirroring (reflection across the x-axis),
+/// followed by a rotation of `angle` around the origin, followed by a `translation`.
+///
+/// Applied to a point `p`, it gives `R(angle) * M * p + translation` where `M` is the
+/// reflection when `mirrored` is set and the identity otherwise.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Transform2D<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    /// The rotation angle.
+    angle: Radian<<T as HasValue>::Output>,
+    /// Whether the y-axis is flipped before rotating.
+    mirrored: bool,
+    /// The translation applied after the rotation.
+    translation: Vector<T>,
+}
+
+//-------------------------------------------------- New --------------------------------------------------
+
+impl<T> Transform2D<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    pub fn new(angle: Radian<<T as HasValue>::Output>, translation: Vector<T>) -> Self {
+        Self {
+            angle,
+            mirrored: false,
+            translation,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(Radian::ZERO, Vector::new(T::ZERO, T::ZERO))
+    }
+
+    pub fn rotation(angle: Radian<<T as HasValue>::Output>) -> Self {
+        Self::new(angle, Vector::new(T::ZERO, T::ZERO))
+    }
+
+    pub fn translation(translation: Vector<T>) -> Self {
+        Self::new(Radian::ZERO, translation)
+    }
+
+    /// Returns the reflection across the x-axis.
+    pub fn mirror() -> Self {
+        Self {
+            angle: Radian::ZERO,
+            mirrored: true,
+            translation: Vector::new(T::ZERO, T::ZERO),
+        }
+    }
+
+    pub fn angle(&self) -> Radian<<T as HasValue>::Output> {
+        self.angle
+    }
+
+    pub fn is_mirrored(&self) -> bool {
+        self.mirrored
+    }
+
+    pub fn translation_vector(&self) -> &Vector<T> {
+        &self.translation
+    }
+}
+
+impl<T> Transform2D<T>
+where
+    T: Number + AngleFactory,
+    <T as HasValue>::Output: AngleOps,
+{
+    /// Returns the transform from the frame attached to `pose` (x-axis along the orientation)
+    /// to the frame in which `pose` is expressed.
+    pub fn from_pose(pose: &Pose<T>) -> Self {
+        let angle = pose.orientation().to_point().angle();
+        Self::new(angle, pose.position().to_vector())
+    }
+}
+
+//-------------------------------------------------- Apply --------------------------------------------------
+
+impl<T> Transform2D<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Neg<Output = T>,
+{
+    pub fn apply_to_vector(&self, vector: &Vector<T>) -> Vector<T> {
+        let dx = vector.dx;
+        let dy = if self.mirrored { -vector.dy } else { vector.dy };
+        let cos = self.angle.cos();
+        let sin = self.angle.sin();
+        Vector::new(dx * cos - dy * sin, dx * sin + dy * cos)
+    }
+
+    pub fn apply_to_point(&self, point: &Point<T>) -> Point<T> {
+        let v = self.apply_to_vector(&point.to_vector());
+        Point::new(v.dx + self.translation.dx, v.dy + self.translation.dy)
+    }
+
+    /// Transforms a polar angle (for instance the start angle of an arc).
+    pub fn apply_to_angle(
+        &self,
+        angle: Radian<<T as HasValue>::Output>,
+    ) -> Radian<<T as HasValue>::Output> {
+        if self.mirrored {
+            self.angle - angle
+        } else {
+            self.angle + angle
+        }
+    }
+
+    /// Transforms a direction of rotation: mirroring flips it.
+    pub fn apply_to_direction(&self, direction: Direction) -> Direction {
+        if self.mirrored {
+            direction.reversed()
+        } else {
+            direction
+        }
+    }
+
+    pub fn apply<S: Transform<T>>(&self, shape: &S) -> S {
+        shape.transformed(self)
+    }
+
+    /// Returns the transform applying `other` first and then `self`.
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            angle: self.apply_to_angle(other.angle),
+            mirrored: self.mirrored != other.mirrored,
+            translation: self
+                .apply_to_point(&other.translation.to_point())
+                .to_vector(),
+        }
+    }
+
+    pub fn inverse(&self) -> Self {
+        let angle = if self.mirrored {
+            self.angle
+        } else {
+            Radian::ZERO - self.angle
+        };
+        let linear = Self {
+            angle,
+            mirrored: self.mirrored,
+            translation: Vector::new(T::ZERO, T::ZERO),
+        };
+        let translation = linear.apply_to_vector(&self.translation).reversed();
+        Self {
+            angle,
+            mirrored: self.mirrored,
+            translation,
+        }
+    }
+}
+
+//-------------------------------------------------- Ops --------------------------------------------------
+
+impl<T> Mul<Transform2D<T>> for Transform2D<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Transform2D<T>) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+
+impl<T> Mul<&Transform2D<T>> for &Transform2D<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Neg<Output = T>,
+{
+    type Output = Transform2D<T>;
+
+    fn mul(self, rhs: &Transform2D<T>) -> Self::Output {
+        self.compose(rhs)
+    }
+}
+
+//-------------------------------------------------- Transform --------------------------------------------------
+
+/// A shape that can be moved by a rigid-body transform.
+pub trait Transform<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self;
+
+    fn transform(&mut self, transform: &Transform2D<T>)
+    where
+        Self: Sized,
+    {
+        *self = self.transformed(transform);
+    }
+}
+
+//-------------------------------------------------- Display --------------------------------------------------
+
+impl<T> Display for Transform2D<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Transform2D({}, {}, {})",
+            self.angle.to_degrees(),
+            self.mirrored,
+            self.translation
+        )
+    }
+}
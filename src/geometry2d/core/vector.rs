@@ -87,6 +87,17 @@ where
     }
 }
 
+//-------------------------------------------------- ToPoint --------------------------------------------------
+
+impl<T> Vector<T>
+where
+    T: Number,
+{
+    pub fn to_point(&self) -> Point<T> {
+        Point::new(self.dx, self.dy)
+    }
+}
+
 //-------------------------------------------------- ToVector --------------------------------------------------
 
 // impl<T> ToVector<T> for Vector<T>
@@ -98,6 +109,20 @@ where
 //     }
 // }
 
+//-------------------------------------------------- Transform --------------------------------------------------
+
+impl<T> Transform<T> for Vector<T>
+where
+    T: Number,
+    <T as HasValue>::Output: AngleOps,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: std::ops::Neg<Output = T>,
+{
+    fn transformed(&self, transform: &Transform2D<T>) -> Self {
+        transform.apply_to_vector(self)
+    }
+}
+
 //-------------------------------------------------- Ops --------------------------------------------------
 
 //------------------------- Add -------------------------
@@ -0,0 +1,839 @@
+//! Well-Known Text reading and writing.
+//!
+//! | Shape | WKT |
+//! |---|---|
+//! | `Point` | `POINT` |
+//! | `Segment` | `LINESTRING` of two points |
+//! | `Polygon`, `PolygonWithHoles` | `POLYGON` |
+//! | `MultiPolygon` | `MULTIPOLYGON` |
+//! | `Vec<Point>` | `MULTIPOINT` |
+//! | `Vec<Segment>` | `MULTILINESTRING` |
+//! | `DirectedArc` | `CIRCULARSTRING` of its start, middle and finish points |
+//! | `Trajectory` | `COMPOUNDCURVE` of its rotations and transition |
+//! | `Path` | `MULTICURVE` of the trajectories |
+//!
+//! Coordinates are the values of the numbers (`x.value()`). Keywords are case insensitive and
+//! coordinates beyond the first two (`Z`, `M`) are ignored.
+
+use super::*;
+use std::f64::consts::PI;
+use std::fmt::Display;
+use std::ops::Mul;
+
+use sity::*;
+
+/// An error while reading WKT, at a byte offset of the text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WktError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl WktError {
+    pub fn new(position: usize, message: impl Into<String>) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for WktError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for WktError {}
+
+/// A shape written as, and read from, Well-Known Text.
+pub trait Wkt: Sized {
+    fn to_wkt(&self) -> String;
+    fn from_wkt(text: &str) -> Result<Self, WktError>;
+}
+
+//-------------------------------------------------- Parser --------------------------------------------------
+
+type P = [f64; 2];
+
+/// A component of a `COMPOUNDCURVE`.
+enum Curve {
+    Line(Vec<P>),
+    Arc([P; 3]),
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    fn error(&self, message: impl Into<String>) -> WktError {
+        WktError::new(self.position, message)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    /// Skips the spaces and returns the next character.
+    fn peek(&mut self) -> Option<char> {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), WktError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{}`", c)))
+        }
+    }
+
+    /// Reads a word, in upper case.
+    fn word(&mut self) -> String {
+        self.peek();
+        let length = self
+            .rest()
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(self.rest().len());
+        let word = self.rest()[..length].to_ascii_uppercase();
+        self.position += length;
+        word
+    }
+
+    /// Reads the keyword of a geometry, with its optional dimensions. Returns whether the
+    /// geometry is `EMPTY`.
+    fn tag(&mut self, keyword: &str) -> Result<bool, WktError> {
+        let start = self.position;
+        let word = self.word();
+        if word != keyword {
+            self.position = start;
+            self.peek();
+            return Err(self.error(format!("expected `{}`", keyword)));
+        }
+        let start = self.position;
+        match self.word().as_str() {
+            "EMPTY" => return Ok(true),
+            "Z" | "M" | "ZM" => {}
+            _ => self.position = start,
+        }
+        let start = self.position;
+        match self.word().as_str() {
+            "EMPTY" => Ok(true),
+            _ => {
+                self.position = start;
+                Ok(false)
+            }
+        }
+    }
+
+    fn end(&mut self) -> Result<(), WktError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.error("unexpected text")),
+        }
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        self.peek();
+        let length = self
+            .rest()
+            .find(|c: char| !(c.is_ascii_digit() || "+-.eE".contains(c)))
+            .unwrap_or(self.rest().len());
+        let value = self.rest()[..length].parse().ok()?;
+        self.position += length;
+        Some(value)
+    }
+
+    fn coordinates(&mut self) -> Result<P, WktError> {
+        let x = self
+            .number()
+            .ok_or_else(|| self.error("expected a number"))?;
+        let y = self
+            .number()
+            .ok_or_else(|| self.error("expected a number"))?;
+        while self.number().is_some() {}
+        Ok([x, y])
+    }
+
+    /// Reads `(item, item, ...)`.
+    fn list<R>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<R, WktError>,
+    ) -> Result<Vec<R>, WktError> {
+        self.expect('(')?;
+        let mut items = vec![item(self)?];
+        while self.eat(',') {
+            items.push(item(self)?);
+        }
+        self.expect(')')?;
+        Ok(items)
+    }
+
+    /// Reads `(x y, x y, ...)`, with its position.
+    fn points(&mut self) -> Result<(usize, Vec<P>), WktError> {
+        self.peek();
+        let start = self.position;
+        Ok((start, self.list(Self::coordinates)?))
+    }
+
+    /// Reads the points of a `CIRCULARSTRING` of a single arc.
+    fn arc(&mut self) -> Result<(usize, [P; 3]), WktError> {
+        let (start, points) = self.points()?;
+        match points[..] {
+            [a, b, c] => Ok((start, [a, b, c])),
+            _ => Err(WktError::new(start, "expected the 3 points of an arc")),
+        }
+    }
+
+    fn curve(&mut self) -> Result<(usize, Curve), WktError> {
+        if self.peek() == Some('(') {
+            let (start, points) = self.points()?;
+            return Ok((start, Curve::Line(points)));
+        }
+        let start = self.position;
+        match self.word().as_str() {
+            "LINESTRING" => {
+                let (_, points) = self.points()?;
+                Ok((start, Curve::Line(points)))
+            }
+            "CIRCULARSTRING" => {
+                let (_, points) = self.arc()?;
+                Ok((start, Curve::Arc(points)))
+            }
+            _ => Err(WktError::new(start, "expected a curve")),
+        }
+    }
+
+    fn compound(&mut self) -> Result<(usize, Vec<(usize, Curve)>), WktError> {
+        self.peek();
+        let start = self.position;
+        self.tag("COMPOUNDCURVE")?;
+        Ok((start, self.list(Self::curve)?))
+    }
+}
+
+//-------------------------------------------------- Conversions --------------------------------------------------
+
+fn number<T>(v: f64) -> T
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    T::ONE * (v / T::ONE.value())
+}
+
+fn point<T>(p: P) -> Point<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    Point::new(number(p[0]), number(p[1]))
+}
+
+fn write_points<'a, T>(points: impl IntoIterator<Item = &'a Point<T>>) -> String
+where
+    T: Number + 'a,
+    T: HasValue<Output = f64>,
+{
+    let points = points
+        .into_iter()
+        .map(|p| format!("{} {}", p.x.value(), p.y.value()))
+        .collect::<Vec<_>>();
+    format!("({})", points.join(", "))
+}
+
+fn write_list(items: Vec<String>) -> String {
+    if items.is_empty() {
+        " EMPTY".to_string()
+    } else {
+        format!(" ({})", items.join(", "))
+    }
+}
+
+/// A closed ring, its first point repeated at the end.
+fn write_ring<T>(polygon: &Polygon<T>) -> String
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    write_points(polygon.points().iter().chain(polygon.points().first()))
+}
+
+fn read_ring<T>((start, mut points): (usize, Vec<P>)) -> Result<Polygon<T>, WktError>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    if points.len() < 4 || points.first() != points.last() {
+        return Err(WktError::new(start, "expected a closed ring"));
+    }
+    points.pop();
+    Polygon::new(points.into_iter().map(point).collect())
+        .map_err(|e| WktError::new(start, e.to_string()))
+}
+
+fn read_polygon<T>(parser: &mut Parser) -> Result<PolygonWithHoles<T>, WktError>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    let mut rings = parser
+        .list(Parser::points)?
+        .into_iter()
+        .map(read_ring)
+        .collect::<Result<Vec<_>, _>>()?;
+    let outer = rings.remove(0);
+    Ok(PolygonWithHoles::new(outer, rings))
+}
+
+fn write_polygon<T>(polygon: &PolygonWithHoles<T>) -> String
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    let rings = std::iter::once(polygon.outer())
+        .chain(polygon.holes())
+        .map(write_ring)
+        .collect::<Vec<_>>();
+    format!("({})", rings.join(", "))
+}
+
+/// The start, middle and finish points of an arc.
+fn arc_points<T>(arc: &DirectedArc<T>) -> [Point<T>; 3]
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    let angle = arc.start_angle().value() + sweep(arc) / 2.0;
+    let middle = Point::new(
+        arc.center().x + arc.radius() * angle.cos(),
+        arc.center().y + arc.radius() * angle.sin(),
+    );
+    [arc.start_point(), middle, arc.finish_point()]
+}
+
+/// The angle swept by the arc, negative clockwise.
+fn sweep<T>(arc: &DirectedArc<T>) -> f64
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    match arc.direction() {
+        Direction::CounterClockWise => arc.delta_angle().value().abs(),
+        Direction::ClockWise => -arc.delta_angle().value().abs(),
+    }
+}
+
+fn write_arc<T>(arc: &DirectedArc<T>) -> String
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    format!("CIRCULARSTRING {}", write_points(&arc_points(arc)))
+}
+
+fn new_arc<T>(
+    center: P,
+    radius: f64,
+    direction: Direction,
+    start: f64,
+    delta: f64,
+) -> DirectedArc<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    let circle = Circle::new(point(center), number(radius));
+    DirectedArc::new(
+        DirectedCircle::new(circle, direction),
+        Radian::new(start),
+        Radian::new(delta),
+    )
+}
+
+/// The arc through three points, `None` when they are equal. Three equal points are the
+/// degenerate arc of a rotation of zero length. When the first and last points are equal, the
+/// arc is a full counter-clockwise turn.
+fn read_arc<T>((start, [a, b, c]): (usize, [P; 3])) -> Result<Option<DirectedArc<T>>, WktError>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    if a == b && b == c {
+        return Ok(None);
+    }
+    if a == c {
+        let center = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+        let radius = (a[0] - center[0]).hypot(a[1] - center[1]);
+        let angle = (a[1] - center[1]).atan2(a[0] - center[0]);
+        let arc = new_arc(center, radius, Direction::CounterClockWise, angle, 2.0 * PI);
+        return Ok(Some(arc));
+    }
+    let (u, v) = ([b[0] - a[0], b[1] - a[1]], [c[0] - a[0], c[1] - a[1]]);
+    let det = u[0] * v[1] - u[1] * v[0];
+    if det == 0.0 || !det.is_finite() {
+        return Err(WktError::new(start, "the points of the arc are collinear"));
+    }
+    let (lu, lv) = (u[0] * u[0] + u[1] * u[1], v[0] * v[0] + v[1] * v[1]);
+    let offset = [
+        (v[1] * lu - u[1] * lv) / (2.0 * det),
+        (u[0] * lv - v[0] * lu) / (2.0 * det),
+    ];
+    let center = [a[0] + offset[0], a[1] + offset[1]];
+    let radius = offset[0].hypot(offset[1]);
+    let angle = |p: P| (p[1] - center[1]).atan2(p[0] - center[0]);
+    let (from, to) = (angle(a), angle(c));
+    let (direction, delta) = if det > 0.0 {
+        (
+            Direction::CounterClockWise,
+            (to - from).rem_euclid(2.0 * PI),
+        )
+    } else {
+        (Direction::ClockWise, (from - to).rem_euclid(2.0 * PI))
+    };
+    Ok(Some(new_arc(center, radius, direction, from, delta)))
+}
+
+/// The unit direction of travel along the arc at `angle`.
+fn tangent<T>(arc: &DirectedArc<T>, angle: f64) -> P
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+{
+    match arc.direction() {
+        Direction::CounterClockWise => [-angle.sin(), angle.cos()],
+        Direction::ClockWise => [angle.sin(), -angle.cos()],
+    }
+}
+
+//-------------------------------------------------- Point --------------------------------------------------
+
+impl<T> Wkt for Point<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    fn to_wkt(&self) -> String {
+        format!("POINT {}", write_points([self]))
+    }
+
+    fn from_wkt(text: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(text);
+        if parser.tag("POINT")? {
+            return Err(parser.error("empty point"));
+        }
+        let (start, points) = parser.points()?;
+        parser.end()?;
+        match points[..] {
+            [p] => Ok(point(p)),
+            _ => Err(WktError::new(start, "expected a single point")),
+        }
+    }
+}
+
+impl<T> Wkt for Vec<Point<T>>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    fn to_wkt(&self) -> String {
+        let points = self.iter().map(|p| write_points([p])).collect();
+        format!("MULTIPOINT{}", write_list(points))
+    }
+
+    /// Both `MULTIPOINT ((x y), ...)` and `MULTIPOINT (x y, ...)` are read.
+    fn from_wkt(text: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(text);
+        if parser.tag("MULTIPOINT")? {
+            return Ok(vec![]);
+        }
+        let points = parser.list(|parser| {
+            if parser.eat('(') {
+                let p = parser.coordinates()?;
+                parser.expect(')')?;
+                Ok(point(p))
+            } else {
+                Ok(point(parser.coordinates()?))
+            }
+        })?;
+        parser.end()?;
+        Ok(points)
+    }
+}
+
+//-------------------------------------------------- Segment --------------------------------------------------
+
+fn read_segment<T>((start, points): (usize, Vec<P>)) -> Result<Segment<T>, WktError>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    match points[..] {
+        [p, q] => Ok(Segment::new(point(p), point(q))),
+        _ => Err(WktError::new(start, "expected the 2 points of a segment")),
+    }
+}
+
+impl<T> Wkt for Segment<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    fn to_wkt(&self) -> String {
+        format!("LINESTRING {}", write_points([self.first(), self.second()]))
+    }
+
+    fn from_wkt(text: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(text);
+        if parser.tag("LINESTRING")? {
+            return Err(parser.error("empty segment"));
+        }
+        let segment = read_segment(parser.points()?)?;
+        parser.end()?;
+        Ok(segment)
+    }
+}
+
+impl<T> Wkt for Vec<Segment<T>>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    fn to_wkt(&self) -> String {
+        let segments = self
+            .iter()
+            .map(|s| write_points([s.first(), s.second()]))
+            .collect();
+        format!("MULTILINESTRING{}", write_list(segments))
+    }
+
+    fn from_wkt(text: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(text);
+        if parser.tag("MULTILINESTRING")? {
+            return Ok(vec![]);
+        }
+        let segments = parser.list(|parser| read_segment(parser.points()?))?;
+        parser.end()?;
+        Ok(segments)
+    }
+}
+
+//-------------------------------------------------- Polygon --------------------------------------------------
+
+impl<T> Wkt for Polygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    fn to_wkt(&self) -> String {
+        format!("POLYGON ({})", write_ring(self))
+    }
+
+    fn from_wkt(text: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(text);
+        if parser.tag("POLYGON")? {
+            return Err(parser.error("empty polygon"));
+        }
+        let start = parser.position;
+        let polygon = read_polygon(&mut parser)?;
+        parser.end()?;
+        if !polygon.holes().is_empty() {
+            return Err(WktError::new(start, "expected a polygon without holes"));
+        }
+        Ok(polygon.outer().clone())
+    }
+}
+
+impl<T> Wkt for PolygonWithHoles<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    fn to_wkt(&self) -> String {
+        format!("POLYGON {}", write_polygon(self))
+    }
+
+    fn from_wkt(text: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(text);
+        if parser.tag("POLYGON")? {
+            return Err(parser.error("empty polygon"));
+        }
+        let polygon = read_polygon(&mut parser)?;
+        parser.end()?;
+        Ok(polygon)
+    }
+}
+
+impl<T> Wkt for MultiPolygon<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+    T: Mul,
+    <T as Mul>::Output: Number,
+{
+    fn to_wkt(&self) -> String {
+        let polygons = self.polygons().iter().map(write_polygon).collect();
+        format!("MULTIPOLYGON{}", write_list(polygons))
+    }
+
+    fn from_wkt(text: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(text);
+        if parser.tag("MULTIPOLYGON")? {
+            return Ok(MultiPolygon::new(vec![]));
+        }
+        let polygons = parser.list(read_polygon)?;
+        parser.end()?;
+        Ok(MultiPolygon::new(polygons))
+    }
+}
+
+//-------------------------------------------------- Arc --------------------------------------------------
+
+impl<T> Wkt for DirectedArc<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    fn to_wkt(&self) -> String {
+        write_arc(self)
+    }
+
+    /// The arc through the 3 points of a `CIRCULARSTRING`, turning the way they turn.
+    fn from_wkt(text: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(text);
+        if parser.tag("CIRCULARSTRING")? {
+            return Err(parser.error("empty arc"));
+        }
+        let (start, points) = parser.arc()?;
+        parser.end()?;
+        read_arc((start, points))?.ok_or_else(|| WktError::new(start, "degenerate arc"))
+    }
+}
+
+//-------------------------------------------------- Trajectory --------------------------------------------------
+
+fn write_trajectory<T>(trajectory: &Trajectory<T>) -> String
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    let transition = match trajectory.transition() {
+        Transition::Segment(seg) => write_points([seg.first(), seg.second()]),
+        Transition::Arc(arc) => write_arc(arc),
+    };
+    format!(
+        "COMPOUNDCURVE ({}, {}, {})",
+        write_arc(trajectory.start_rotation()),
+        transition,
+        write_arc(trajectory.finish_rotation())
+    )
+}
+
+/// A rotation of zero length, at `position` and heading `heading`, on the circle of `radius`
+/// turning in `direction`.
+fn zero_rotation<T>(position: P, heading: P, radius: f64, direction: Direction) -> DirectedArc<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    let normal = match direction {
+        Direction::CounterClockWise => [-heading[1], heading[0]],
+        Direction::ClockWise => [heading[1], -heading[0]],
+    };
+    let center = [
+        position[0] + radius * normal[0],
+        position[1] + radius * normal[1],
+    ];
+    let angle = (-normal[1]).atan2(-normal[0]);
+    new_arc(center, radius, direction, angle, 0.0)
+}
+
+fn pose<T>(position: P, heading: P) -> Pose<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    Pose::new(
+        point(position),
+        Vector::new(number(heading[0]), number(heading[1])),
+    )
+}
+
+/// Reads a trajectory from the 3 components of a `COMPOUNDCURVE`: the start rotation, the
+/// transition (a line of 2 points or an arc) and the finish rotation.
+///
+/// A rotation of zero length is written as 3 equal points, which do not record its circle: it
+/// is read back on the circle tangent to the trajectory with the radius of the other arcs (zero
+/// without any), turning opposite to an arc transition, or counter-clockwise.
+fn read_trajectory<T>(
+    (start, curves): (usize, Vec<(usize, Curve)>),
+) -> Result<Trajectory<T>, WktError>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    let curves: [(usize, Curve); 3] = curves
+        .try_into()
+        .map_err(|_| WktError::new(start, "expected an arc, a transition and an arc"))?;
+    let [(first, first_curve), (middle, transition), (last, last_curve)] = curves;
+    let (Curve::Arc(first_points), Curve::Arc(last_points)) = (first_curve, last_curve) else {
+        return Err(WktError::new(
+            start,
+            "expected an arc, a transition and an arc",
+        ));
+    };
+    let start_rotation = read_arc::<T>((first, first_points))?;
+    let finish_rotation = read_arc::<T>((last, last_points))?;
+
+    // The transition, with its headings at both ends
+    let (transition, from, to) = match transition {
+        Curve::Line(points) => {
+            let seg = read_segment::<T>((middle, points.clone()))?;
+            let (p, q) = (points[0], points[1]);
+            let length = (q[0] - p[0]).hypot(q[1] - p[1]);
+            let heading = if length > 0.0 {
+                [(q[0] - p[0]) / length, (q[1] - p[1]) / length]
+            } else {
+                [1.0, 0.0]
+            };
+            (Transition::Segment(seg), heading, heading)
+        }
+        Curve::Arc(points) => {
+            let arc = read_arc::<T>((middle, points))?
+                .ok_or_else(|| WktError::new(middle, "degenerate transition arc"))?;
+            let from = tangent(&arc, arc.start_angle().value());
+            let to = tangent(&arc, arc.finish_angle().value());
+            (Transition::Arc(arc), from, to)
+        }
+    };
+    let radius = [&start_rotation, &finish_rotation]
+        .into_iter()
+        .flatten()
+        .chain(match &transition {
+            Transition::Arc(arc) => Some(arc),
+            Transition::Segment(_) => None,
+        })
+        .map(|arc| arc.radius().value())
+        .next()
+        .unwrap_or(0.0);
+    let direction = match &transition {
+        Transition::Arc(arc) if arc.direction() == Direction::CounterClockWise => {
+            Direction::ClockWise
+        }
+        _ => Direction::CounterClockWise,
+    };
+
+    let (start_rotation, start_heading) = match start_rotation {
+        Some(arc) => {
+            let heading = tangent(&arc, arc.start_angle().value());
+            (arc, heading)
+        }
+        None => (
+            zero_rotation(first_points[0], from, radius, direction),
+            from,
+        ),
+    };
+    let (finish_rotation, finish_heading) = match finish_rotation {
+        Some(arc) => {
+            let heading = tangent(&arc, arc.finish_angle().value());
+            (arc, heading)
+        }
+        None => (zero_rotation(last_points[2], to, radius, direction), to),
+    };
+    Ok(Trajectory::new(
+        pose(first_points[0], start_heading),
+        start_rotation,
+        transition,
+        finish_rotation,
+        pose(last_points[2], finish_heading),
+    ))
+}
+
+impl<T> Wkt for Trajectory<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    fn to_wkt(&self) -> String {
+        write_trajectory(self)
+    }
+
+    /// Reads a `COMPOUNDCURVE` of a start rotation, a transition and a finish rotation. Poses
+    /// are read with unit orientations.
+    fn from_wkt(text: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(text);
+        let trajectory = read_trajectory(parser.compound()?)?;
+        parser.end()?;
+        Ok(trajectory)
+    }
+}
+
+//-------------------------------------------------- Path --------------------------------------------------
+
+impl<T> Wkt for Path<T>
+where
+    T: Number,
+    T: HasValue<Output = f64>,
+    T: Mul<<T as HasValue>::Output, Output = T>,
+{
+    fn to_wkt(&self) -> String {
+        let trajectories = self.trajectories().iter().map(write_trajectory).collect();
+        format!("MULTICURVE{}", write_list(trajectories))
+    }
+
+    fn from_wkt(text: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(text);
+        if parser.tag("MULTICURVE")? {
+            return Ok(Path::new(vec![]));
+        }
+        let trajectories = parser.list(|parser| read_trajectory(parser.compound()?))?;
+        parser.end()?;
+        Ok(Path::new(trajectories))
+    }
+}
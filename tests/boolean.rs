@@ -0,0 +1,103 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::*;
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+
+    #[test]
+    fn test_boolean_overlapping_squares() {
+        let a = rectangle(0.0, 0.0, 2.0, 2.0);
+        let b = rectangle(1.0, 1.0, 3.0, 3.0);
+
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union.polygons()[0].outer().points().len(), 8);
+        assert_approx_eq!(union.area(), 7.0);
+
+        let intersection = a.intersection(&b);
+        assert_eq!(intersection.len(), 1);
+        assert_approx_eq!(intersection.area(), 1.0);
+
+        let difference = a.difference(&b);
+        assert_eq!(difference.len(), 1);
+        assert_eq!(difference.polygons()[0].outer().points().len(), 6);
+        assert_approx_eq!(difference.area(), 3.0);
+
+        let xor = a.xor(&b);
+        assert_eq!(xor.len(), 2);
+        assert_approx_eq!(xor.area(), 6.0);
+    }
+
+    #[test]
+    fn test_boolean_hole() {
+        let field = rectangle(0.0, 0.0, 4.0, 4.0);
+        let pond = rectangle(1.0, 1.0, 3.0, 3.0);
+        let result = field.difference(&pond);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result.polygons()[0].holes().len(), 1);
+        assert_approx_eq!(result.area(), 12.0);
+        assert!(!result.contains(&Point::new(2.0, 2.0)));
+        assert!(result.contains(&Point::new(0.5, 2.0)));
+
+        // Filling the hole back
+        let filled = result.union(&pond.into());
+        assert_eq!(filled.len(), 1);
+        assert!(filled.polygons()[0].holes().is_empty());
+        assert_approx_eq!(filled.area(), 16.0);
+    }
+
+    #[test]
+    fn test_boolean_concave() {
+        // U shape clipped by a horizontal band: the two arms remain
+        let u = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ])
+        .unwrap();
+        let band = rectangle(-1.0, 2.0, 4.0, 4.0);
+        let result = u.intersection(&band);
+        assert_eq!(result.len(), 2);
+        assert_approx_eq!(result.area(), 2.0);
+
+        let union = u.union(&band);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union.polygons()[0].holes().len(), 1);
+        assert_approx_eq!(union.area(), 7.0 + 10.0 - 2.0);
+    }
+
+    #[test]
+    fn test_boolean_touching_and_disjoint() {
+        let a = rectangle(0.0, 0.0, 1.0, 1.0);
+        let b = rectangle(1.0, 0.0, 2.0, 1.0);
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union.polygons()[0].outer().points().len(), 4);
+        assert_approx_eq!(union.area(), 2.0);
+        assert!(a.intersection(&b).is_empty());
+
+        let c = rectangle(5.0, 5.0, 6.0, 6.0);
+        assert_eq!(a.union(&c).len(), 2);
+        assert!(a.intersection(&c).is_empty());
+        assert_approx_eq!(a.difference(&c).area(), 1.0);
+        assert_approx_eq!(a.difference(&a).area(), 0.0);
+    }
+
+    #[test]
+    fn test_boolean_mapping() {
+        let field = rectangle(0.0, 0.0, 10.0, 10.0);
+        let pylon = rectangle(4.0, 4.0, 6.0, 6.0);
+        let result = field.difference(&pylon);
+        let segments = result.polygons()[0].mapping(&Vector::new(1.0, 0.0), 0.7);
+        assert!(!segments.is_empty());
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        assert!(Path::optimal_mapping(&start, 1.0, 1.0, &result).is_some());
+    }
+}
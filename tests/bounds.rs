@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+    use sity::*;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn assert_point_eq(p1: &Point<f64>, p2: &Point<f64>) {
+        assert_approx_eq!(p1.x, p2.x, 1e-10);
+        assert_approx_eq!(p1.y, p2.y, 1e-10);
+    }
+
+    #[test]
+    fn test_aabb_ops() {
+        let a = Aabb::new(Point::new(2.0, 0.0), Point::new(0.0, 2.0));
+        let b = Aabb::new(Point::new(1.0, 1.0), Point::new(3.0, 4.0));
+        assert_eq!(a.min(), &Point::new(0.0, 0.0));
+        let u = a.union(&b);
+        assert_eq!(u, Aabb::new(Point::new(0.0, 0.0), Point::new(3.0, 4.0)));
+        assert!(u.contains(&a) && u.contains(&b));
+        assert!(!a.contains(&b));
+        let i = a.intersection(&b).unwrap();
+        assert_eq!(i, Aabb::new(Point::new(1.0, 1.0), Point::new(2.0, 2.0)));
+        assert!(i.contains(&Point::new(2.0, 1.5)));
+        let c = Aabb::new(Point::new(5.0, 5.0), Point::new(6.0, 6.0));
+        assert!(!a.intersects(&c));
+        assert_eq!(a.expanded(1.0).width(), 4.0);
+    }
+
+    #[test]
+    fn test_aabb_arc_tight() {
+        // Quarter of the unit circle from 45 to 135 degrees, counter-clockwise: goes through the top
+        let circle = DirectedCircle::new(
+            Circle::new(Point::new(0.0, 0.0), 1.0),
+            Direction::CounterClockWise,
+        );
+        let arc = DirectedArc::new(circle, Radian::new(FRAC_PI_4), Radian::new(FRAC_PI_2));
+        let b = arc.aabb();
+        let s = FRAC_PI_4.sin();
+        assert_point_eq(b.min(), &Point::new(-s, s));
+        assert_point_eq(b.max(), &Point::new(s, 1.0));
+
+        // The other way round, the arc covers the rest of the circle
+        let circle =
+            DirectedCircle::new(Circle::new(Point::new(0.0, 0.0), 1.0), Direction::ClockWise);
+        let arc = DirectedArc::new(circle, Radian::new(FRAC_PI_4), Radian::new(1.5 * PI));
+        let b = arc.aabb();
+        assert_point_eq(b.min(), &Point::new(-1.0, -1.0));
+        assert_point_eq(b.max(), &Point::new(1.0, s));
+    }
+
+    #[test]
+    fn test_aabb_path() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(1.0, 0.0), Vector::new(-1.0, 0.0));
+        let path = Path::create(1.0, &vec![start, finish]).unwrap();
+        let b = path.aabb();
+        let samples = path
+            .trajectories()
+            .iter()
+            .flat_map(|traj| {
+                let mut points = traj.start_rotation().discretize(1000);
+                match traj.transition() {
+                    Transition::Segment(seg) => points.push(seg.second().clone()),
+                    Transition::Arc(arc) => points.extend(arc.discretize(1000)),
+                }
+                points.extend(traj.finish_rotation().discretize(1000));
+                points
+            })
+            .collect::<Vec<_>>();
+        // Contains the path and is reached by it
+        let sampled = Aabb::from_points(&samples).unwrap();
+        assert!(b.expanded(1e-9).contains(&sampled));
+        assert!(sampled.expanded(1e-2).contains(&b));
+    }
+
+    #[test]
+    fn test_min_area_box() {
+        // A 4 x 1 rectangle turned by 30 degrees
+        let (cos, sin) = ((PI / 6.0).cos(), (PI / 6.0).sin());
+        let points = [(0.0, 0.0), (4.0, 0.0), (4.0, 1.0), (2.0, 0.5), (0.0, 1.0)]
+            .map(|(x, y)| Point::new(1.0 + x * cos - y * sin, 2.0 + x * sin + y * cos))
+            .to_vec();
+        let polygon = Polygon::new(points).unwrap();
+        let obb = polygon.min_area_box().unwrap();
+        assert_approx_eq!(obb.length() * obb.width(), 4.0, 1e-10);
+        assert_approx_eq!(obb.length().max(obb.width()), 4.0, 1e-10);
+        for c in obb.corners() {
+            assert_approx_eq!(c.distance(obb.center()), 17.0_f64.sqrt() / 2.0, 1e-10);
+        }
+        assert_point_eq(
+            obb.center(),
+            &Point::new(1.0 + 2.0 * cos - 0.5 * sin, 2.0 + 2.0 * sin + 0.5 * cos),
+        );
+    }
+}
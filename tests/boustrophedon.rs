@@ -0,0 +1,71 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::*;
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+
+    #[test]
+    fn test_boustrophedon_cells() {
+        // Horizontal passes: the base and the two arms
+        let cells = u_shape().boustrophedon_cells(&Vector::new(1.0, 0.0));
+        assert_eq!(cells.len(), 3);
+        let mut areas = cells.iter().map(Polygon::area).collect::<Vec<_>>();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_approx_eq!(areas[0], 4.0);
+        assert_approx_eq!(areas[1], 4.0);
+        assert_approx_eq!(areas[2], 5.0);
+        for cell in cells.iter() {
+            assert!(cell.is_convex());
+        }
+
+        // Vertical passes never leave the U: a single cell
+        let cells = u_shape().boustrophedon_cells(&Vector::new(0.0, 1.0));
+        assert_eq!(cells.len(), 1);
+        assert_approx_eq!(cells[0].area(), 13.0);
+
+        // No direction to sweep along
+        assert!(u_shape()
+            .boustrophedon_cells(&Vector::new(0.0, 0.0))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_boustrophedon_cells_holes() {
+        let field = PolygonWithHoles::new(
+            rectangle(0.0, 0.0, 10.0, 10.0),
+            vec![rectangle(4.0, 4.0, 6.0, 6.0)],
+        );
+        let cells = field.boustrophedon_cells(&Vector::new(1.0, 1.0));
+        let total: f64 = cells.iter().map(Polygon::area).sum();
+        assert_approx_eq!(total, 96.0, 1e-9);
+        assert_eq!(cells.len(), 4);
+    }
+
+    #[test]
+    fn test_boustrophedon_mapping() {
+        let u = u_shape();
+        let start = Pose::new(Point::new(0.0, -1.0), Vector::new(1.0, 0.0));
+        let path = Path::boustrophedon_mapping(
+            &start,
+            &Vector::new(1.0, 0.0),
+            0.2,
+            0.5,
+            &u.clone().into(),
+        )
+        .unwrap();
+        // Every pass lies inside the field
+        let passes = path
+            .trajectories()
+            .iter()
+            .filter_map(|traj| traj.segment())
+            .filter(|seg| (seg.first().y - seg.second().y).abs() < 1e-9 && seg.first().y > 0.0)
+            .collect::<Vec<_>>();
+        assert!(!passes.is_empty());
+        for seg in passes {
+            let middle = Point::new((seg.first().x + seg.second().x) / 2.0, seg.first().y);
+            assert!(u.contains(&middle));
+        }
+    }
+}
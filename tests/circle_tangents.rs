@@ -1,22 +1,103 @@
 #[cfg(test)]
 mod tests {
-    // use geomety::geometry2d::*;
-
-    // #[test]
-    // fn test_circle_tangents() {
-    //     let p1 = Point::new(0.0, 0.0);
-    //     let r1 = 1.0;
-    //     let c1 = Circle::new(p1, r1);
-    //     let p2 = Point::new(10.0, 0.0);
-    //     let r2 = 1.0;
-    //     let c2 = Circle::new(p2, r2);
-
-    //     let (t1, t2) = c1.tangents(&c2);
-    //     assert_eq!(t1.is_some(), true);
-    //     assert_eq!(t2.is_some(), true);
-
-    //     let (s1, s2) = t1.unwrap();
-    //     let s = Segment::new(Point::new(0.0, 1.0), Point::new(0.0, -1.0));
-    //     assert_eq!(s1, s);
-    // }
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+
+    fn assert_point_eq(p1: &Point<f64>, p2: &Point<f64>) {
+        assert_approx_eq!(p1.x, p2.x, 1e-10);
+        assert_approx_eq!(p1.y, p2.y, 1e-10);
+    }
+
+    fn assert_tangent(segment: &Segment<f64>, c1: &Circle<f64>, c2: &Circle<f64>) {
+        let v = [
+            segment.second().x - segment.first().x,
+            segment.second().y - segment.first().y,
+        ];
+        for (p, c) in [(segment.first(), c1), (segment.second(), c2)] {
+            let r = [p.x - c.center().x, p.y - c.center().y];
+            assert_approx_eq!(r[0].hypot(r[1]), c.radius(), 1e-10);
+            assert_approx_eq!(r[0] * v[0] + r[1] * v[1], 0.0, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_circle_tangents_separate() {
+        let c1 = Circle::new(Point::new(0.0, 0.0), 2.0);
+        let c2 = Circle::new(Point::new(10.0, 0.0), 1.0);
+        match c1.tangents(&c2) {
+            CommonTangents::Separate { external, internal } => {
+                for s in [&external.0, &external.1, &internal.0, &internal.1] {
+                    assert_tangent(s, &c1, &c2);
+                }
+                // The first tangent of each pair is on the left
+                assert!(external.0.first().y > 0.0 && external.1.first().y < 0.0);
+                assert!(internal.0.first().y > 0.0 && internal.0.second().y < 0.0);
+                assert_approx_eq!(external.0.length(), 99.0_f64.sqrt(), 1e-10);
+                assert_approx_eq!(internal.0.length(), 91.0_f64.sqrt(), 1e-10);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_circle_tangents_degenerate() {
+        let c1 = Circle::new(Point::new(0.0, 0.0), 2.0);
+        assert_eq!(c1.tangents(&c1), CommonTangents::Coincident);
+        let nested = Circle::new(Point::new(0.5, 0.0), 1.0);
+        assert_eq!(c1.tangents(&nested), CommonTangents::Nested);
+        let inside = Circle::new(Point::new(1.0, 0.0), 1.0);
+        match inside.tangents(&c1) {
+            CommonTangents::InternallyTangent(p) => assert_point_eq(&p, &Point::new(2.0, 0.0)),
+            other => panic!("unexpected {:?}", other),
+        }
+        let touching = Circle::new(Point::new(3.0, 0.0), 1.0);
+        match c1.tangents(&touching) {
+            CommonTangents::ExternallyTangent { external, contact } => {
+                assert_point_eq(&contact, &Point::new(2.0, 0.0));
+                assert_tangent(&external.0, &c1, &touching);
+                assert_tangent(&external.1, &c1, &touching);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        let crossing = Circle::new(Point::new(2.0, 0.0), 1.0);
+        assert!(matches!(
+            c1.tangents(&crossing),
+            CommonTangents::Intersecting { .. }
+        ));
+    }
+
+    #[test]
+    fn test_directed_circle_tangent_to() {
+        let c1 = Circle::new(Point::new(0.0, 0.0), 2.0);
+        let c2 = Circle::new(Point::new(10.0, 0.0), 1.0);
+        let left = DirectedCircle::new(c1.clone(), Direction::CounterClockWise);
+        let right = DirectedCircle::new(c2.clone(), Direction::ClockWise);
+        // Leaving the bottom of a counterclockwise circle towards +x, crossing to the top of the other
+        let s = left.tangent_to(&right).unwrap();
+        assert_tangent(&s, &c1, &c2);
+        assert!(s.first().y < 0.0 && s.second().y > 0.0);
+
+        let same = DirectedCircle::new(c2, Direction::CounterClockWise);
+        let s = left.tangent_to(&same).unwrap();
+        assert!(s.first().y < 0.0 && s.second().y < 0.0);
+    }
+
+    #[test]
+    fn test_directed_circle_tangent_to_point() {
+        let c = Circle::new(Point::new(0.0, 0.0), 1.0);
+        let point = Point::new(2.0, 0.0);
+        let ccw = DirectedCircle::new(c.clone(), Direction::CounterClockWise);
+        let s = ccw.tangent_to_point(&point).unwrap();
+        assert_point_eq(s.second(), &point);
+        assert_approx_eq!(s.length(), 3.0_f64.sqrt(), 1e-10);
+        assert!(s.first().y < 0.0);
+
+        let cw = DirectedCircle::new(c, Direction::ClockWise);
+        assert!(cw.tangent_to_point(&point).unwrap().first().y > 0.0);
+
+        // On the circle the tangent is empty, inside there is none
+        let s = cw.tangent_to_point(&Point::new(0.0, 1.0)).unwrap();
+        assert_approx_eq!(s.length(), 0.0, 1e-10);
+        assert!(cw.tangent_to_point(&Point::new(0.5, 0.0)).is_none());
+    }
 }
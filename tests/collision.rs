@@ -0,0 +1,112 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::*;
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+    use sity::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_segment_collision() {
+        let seg = Segment::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0));
+        let contact = seg.first_contact(&square(5.0, 0.0, 1.0)).unwrap();
+        assert_approx_eq!(contact.position, 4.0);
+        assert_approx_eq!(contact.point.x, 4.0);
+
+        let circle = Circle::new(Point::new(6.0, 3.0), 1.0);
+        assert!(!seg.collides(&circle));
+        assert_approx_eq!(seg.clearance(&circle), 2.0);
+        assert_approx_eq!(seg.clearance(&square(5.0, 4.0, 1.0)), 3.0);
+        assert_approx_eq!(seg.clearance(&square(5.0, 0.0, 1.0)), 0.0);
+        // Facing the middle of a long edge
+        let short = Segment::new(Point::new(4.0, 3.0), Point::new(6.0, 3.0));
+        assert_approx_eq!(short.clearance(&rectangle(0.0, -1.0, 10.0, 1.0)), 2.0);
+
+        // Starting inside
+        let contact = seg
+            .first_contact(&Circle::new(Point::new(0.0, 0.0), 1.0))
+            .unwrap();
+        assert_approx_eq!(contact.position, 0.0);
+
+        // Touching a vertex far from the origin
+        let far = Segment::new(Point::new(1e6, 0.0), Point::new(1e6 + 10.0, 0.0));
+        let contact = far
+            .first_contact(
+                &Polygon::new(vec![
+                    Point::new(1e6 + 5.0, 0.0),
+                    Point::new(1e6 + 6.0, 1.0),
+                    Point::new(1e6 + 4.0, 1.0),
+                ])
+                .unwrap(),
+            )
+            .unwrap();
+        assert_approx_eq!(contact.position, 5.0, 1e-6);
+    }
+
+    #[test]
+    fn test_arc_collision() {
+        // Quarter circle from (1, 0) to (0, 1) around the origin
+        let arc = DirectedArc::new(
+            DirectedCircle::new(
+                Circle::new(Point::new(0.0, 0.0), 1.0),
+                Direction::CounterClockWise,
+            ),
+            Radian::new(0.0),
+            Radian::new(FRAC_PI_2),
+        );
+        // Wall x + y = sqrt(2) touches the arc at its middle
+        let wall = Polygon::new(vec![
+            Point::new(2.0_f64.sqrt(), 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(0.0, 2.0_f64.sqrt()),
+        ])
+        .unwrap();
+        let contact = arc.first_contact(&wall).unwrap();
+        assert_approx_eq!(contact.position, PI / 4.0, 1e-5);
+        // Wall x + y = 1.3 crosses it twice
+        let wall = Polygon::new(vec![
+            Point::new(1.3, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(0.0, 1.3),
+        ])
+        .unwrap();
+        let contact = arc.first_contact(&wall).unwrap();
+        assert_approx_eq!(contact.position, (1.3 / 2.0_f64.sqrt()).asin() - PI / 4.0);
+        assert_approx_eq!(contact.point.x + contact.point.y, 1.3);
+
+        let circle = Circle::new(Point::new(0.0, 3.0), 1.0);
+        assert!(!arc.collides(&circle));
+        assert_approx_eq!(arc.clearance(&circle), 1.0);
+        // Behind the arc: closest to its start
+        let circle = Circle::new(Point::new(1.0, -2.0), 1.0);
+        assert_approx_eq!(arc.clearance(&circle), 1.0);
+        let contact = arc
+            .first_contact(&Circle::new(Point::new(0.0, 1.0), 0.5))
+            .unwrap();
+        assert_approx_eq!(contact.point.distance(&Point::new(0.0, 1.0)), 0.5);
+        // On the circle of the arc, past its end
+        assert!(arc.first_contact(&square(-1.0, 0.0, 0.2)).is_none());
+        assert!(!arc.collides(&square(-1.0, 0.0, 0.2)));
+    }
+
+    #[test]
+    fn test_path_collision() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let first = Pose::new(Point::new(10.0, 0.0), Vector::new(1.0, 0.0));
+        let second = Pose::new(Point::new(20.0, 0.0), Vector::new(1.0, 0.0));
+        let path = Path::create(1.0, &vec![start, first, second]).unwrap();
+
+        let obstacle = Obstacle::from(square(15.0, 0.0, 1.0));
+        let contact = path.first_contact(&obstacle).unwrap();
+        assert_approx_eq!(contact.position, 14.0, 1e-9);
+        assert!(path.trajectories()[0].clearance(&obstacle) > 3.99);
+        assert!(!path.trajectories()[0].collides(&obstacle));
+        assert_approx_eq!(
+            path.clearance(&Obstacle::from(Circle::new(Point::new(5.0, 5.0), 2.0))),
+            3.0,
+            1e-9
+        );
+    }
+}
@@ -0,0 +1,33 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use geomety::geometry2d::*;
+
+pub fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> Polygon<f64> {
+    Polygon::new(vec![
+        Point::new(x0, y0),
+        Point::new(x1, y0),
+        Point::new(x1, y1),
+        Point::new(x0, y1),
+    ])
+    .unwrap()
+}
+
+pub fn square(cx: f64, cy: f64, half: f64) -> Polygon<f64> {
+    rectangle(cx - half, cy - half, cx + half, cy + half)
+}
+
+/// U shape open upwards: a 5 x 1 base and two arms 2 wide and 2 high.
+pub fn u_shape() -> Polygon<f64> {
+    Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(5.0, 0.0),
+        Point::new(5.0, 3.0),
+        Point::new(3.0, 3.0),
+        Point::new(3.0, 1.0),
+        Point::new(2.0, 1.0),
+        Point::new(2.0, 3.0),
+        Point::new(0.0, 3.0),
+    ])
+    .unwrap()
+}
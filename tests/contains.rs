@@ -16,4 +16,57 @@ mod tests {
         let point = Point::new(-1.0, 1.0);
         assert_eq!(polygon.contains(&point), false);
     }
+
+    #[test]
+    fn test_concave_polygon_contains() {
+        // U shape, the notch is outside
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ];
+        let polygon = Polygon::new(points).unwrap();
+        assert!(polygon.contains(&Point::new(0.5, 2.0)));
+        assert!(polygon.contains(&Point::new(2.5, 2.0)));
+        assert!(polygon.contains(&Point::new(1.5, 0.5)));
+        assert!(!polygon.contains(&Point::new(1.5, 2.0)));
+        assert!(!polygon.contains(&Point::new(4.0, 1.0)));
+        // On an edge and on a vertex
+        assert!(polygon.contains(&Point::new(1.5, 1.0)));
+        assert!(polygon.contains(&Point::new(2.0, 3.0)));
+        assert_eq!(polygon.winding_number(&Point::new(0.5, 2.0)), 1);
+    }
+
+    #[test]
+    fn test_polygon_classify() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 3.0),
+            Point::new(3.0, 3.0),
+            Point::new(3.0, 0.0),
+        ];
+        let polygon = Polygon::new(points).unwrap();
+        let tolerance = 0.01;
+        assert_eq!(
+            polygon.classify(&Point::new(1.0, 1.0), tolerance),
+            PointPosition::Inside
+        );
+        assert_eq!(
+            polygon.classify(&Point::new(3.005, 1.0), tolerance),
+            PointPosition::OnBoundary
+        );
+        assert_eq!(
+            polygon.classify(&Point::new(1.0, 2.995), tolerance),
+            PointPosition::OnBoundary
+        );
+        assert_eq!(
+            polygon.classify(&Point::new(3.1, 1.0), tolerance),
+            PointPosition::Outside
+        );
+    }
 }
@@ -16,14 +16,15 @@ mod tests {
 
         let expected_hull = vec![
             Point::new(0.0, 0.0),
-            Point::new(0.0, 3.0),
-            Point::new(3.0, 3.0),
             Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(0.0, 3.0),
         ];
 
         let opt = Polygon::convex_hull(points);
         assert_eq!(opt.is_some(), true);
         let hull = opt.unwrap();
         assert_eq!(hull.points(), &expected_hull);
+        assert_eq!(hull.winding(), Orientation::CounterClockWise);
     }
 }
@@ -181,4 +181,39 @@ mod tests {
         let distance = s1.distance(&s2);
         assert_approx_eq!(distance, 0.0, 1e-10);
     }
+
+    #[test]
+    fn test_segment_to_segment_1() {
+        // The closest point of the long segment is inside it
+        let s1 = Segment::new(Point::new(4.0, 3.0), Point::new(6.0, 3.0));
+        let s2 = Segment::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0));
+        assert_approx_eq!(s1.distance(&s2), 3.0, 1e-10);
+        assert_approx_eq!(s2.distance(&s1), 3.0, 1e-10);
+    }
+
+    //-------------------------------------------------- Arc --------------------------------------------------
+
+    #[test]
+    fn test_arc_to_segment() {
+        // Upper half of the unit circle
+        let arc = DirectedArc::new(
+            DirectedCircle::new(
+                Circle::new(Point::new(0.0, 0.0), 1.0),
+                Direction::CounterClockWise,
+            ),
+            Radian::new(0.0),
+            Radian::new(std::f64::consts::PI),
+        );
+        // Facing the middle of the arc
+        let s = Segment::new(Point::new(-5.0, 3.0), Point::new(5.0, 3.0));
+        assert_approx_eq!(arc.distance(&s), 2.0, 1e-10);
+        // Below the arc: closest to its ends
+        let s = Segment::new(Point::new(-5.0, -2.0), Point::new(5.0, -2.0));
+        assert_approx_eq!(arc.distance(&s), 2.0, 1e-10);
+        let s = Segment::new(Point::new(3.0, -1.0), Point::new(3.0, 1.0));
+        assert_approx_eq!(arc.distance(&s), 2.0, 1e-10);
+        // Crossing
+        let s = Segment::new(Point::new(0.0, 0.0), Point::new(0.0, 2.0));
+        assert_approx_eq!(arc.distance(&s), 0.0, 1e-10);
+    }
 }
@@ -0,0 +1,134 @@
+#[cfg(all(test, feature = "geojson"))]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+
+    #[test]
+    fn test_geojson_read_polygons() {
+        let text = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": { "name": "field" },
+                    "geometry": {
+                        "type": "Polygon",
+                        "coordinates": [
+                            [[0, 0], [0, 4], [4, 4], [4, 0], [0, 0]],
+                            [[1, 1], [2, 1], [2, 2], [1, 2], [1, 1]]
+                        ]
+                    }
+                },
+                {
+                    "type": "Feature",
+                    "properties": {},
+                    "geometry": { "type": "Point", "coordinates": [9, 9] }
+                },
+                {
+                    "type": "Feature",
+                    "properties": {},
+                    "geometry": {
+                        "type": "MultiPolygon",
+                        "coordinates": [
+                            [[[10, 0, 5], [11, 0, 5], [10, 1, 5], [10, 0, 5]]],
+                            [[[20, 0], [21, 0], [20, 1]]]
+                        ]
+                    }
+                }
+            ]
+        }"#;
+        let polygons = MultiPolygon::<f64>::from_geojson(text).unwrap();
+        assert_eq!(polygons.len(), 3);
+        let field = &polygons.polygons()[0];
+        assert_eq!(field.outer().points().len(), 4);
+        assert_eq!(field.holes().len(), 1);
+        assert_eq!(field.holes()[0].points().len(), 4);
+        assert_eq!(polygons.polygons()[1].outer().points().len(), 3);
+        assert_eq!(polygons.polygons()[2].outer().points().len(), 3);
+
+        let bare = r#"{"type":"Polygon","coordinates":[[[0,0],[1,0],[0,1],[0,0]]]}"#;
+        assert_eq!(MultiPolygon::<f64>::from_geojson(bare).unwrap().len(), 1);
+
+        let flat = r#"{"type":"Polygon","coordinates":[[[0,0],[1,0],[2,0],[0,0]]]}"#;
+        assert!(matches!(
+            MultiPolygon::<f64>::from_geojson(flat),
+            Err(GeoJsonError::Polygon(_))
+        ));
+        let missing = r#"{"type":"Polygon"}"#;
+        assert!(matches!(
+            MultiPolygon::<f64>::from_geojson(missing),
+            Err(GeoJsonError::Format(_))
+        ));
+        assert!(matches!(
+            MultiPolygon::<f64>::from_geojson("{"),
+            Err(GeoJsonError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_geojson_write() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(0.0, 3.0), Vector::new(-1.0, 0.0));
+        let path = Path::create(1.0, &vec![start.clone(), finish.clone()]).unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&path.to_geojson(0.01).unwrap()).unwrap();
+        assert_eq!(json["geometry"]["type"], "LineString");
+        let points = json["geometry"]["coordinates"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| (p[0].as_f64().unwrap(), p[1].as_f64().unwrap()))
+            .collect::<Vec<_>>();
+        let first = points.first().unwrap();
+        assert_approx_eq!(first.0, 0.0);
+        assert_approx_eq!(first.1, 0.0);
+        let last = points.last().unwrap();
+        assert_approx_eq!(last.0, 0.0);
+        assert_approx_eq!(last.1, 3.0);
+        // A quarter turn around (0, 1), the straight x = 1 and a quarter turn around (0, 2)
+        for w in points.windows(2) {
+            let middle = ((w[0].0 + w[1].0) / 2.0, (w[0].1 + w[1].1) / 2.0);
+            let center = if middle.1 < 1.0 { 1.0 } else { 2.0 };
+            if middle.1 < 1.0 || middle.1 > 2.0 {
+                let gap = 1.0 - middle.0.hypot(middle.1 - center);
+                assert!(gap > 0.0 && gap <= 0.01);
+            } else {
+                assert_approx_eq!(middle.0, 1.0);
+            }
+        }
+        let coarse: serde_json::Value =
+            serde_json::from_str(&path.to_geojson(0.5).unwrap()).unwrap();
+        assert!(coarse["geometry"]["coordinates"].as_array().unwrap().len() < points.len());
+
+        let json: serde_json::Value =
+            serde_json::from_str(&Pose::to_geojson(&[start, finish])).unwrap();
+        let features = json["features"].as_array().unwrap();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[1]["geometry"]["type"], "Point");
+        assert_eq!(features[1]["geometry"]["coordinates"][1], 3.0);
+        assert_approx_eq!(features[0]["properties"]["heading"].as_f64().unwrap(), 0.0);
+        assert_approx_eq!(features[0]["properties"]["bearing"].as_f64().unwrap(), 90.0);
+        assert_approx_eq!(
+            features[1]["properties"]["heading"].as_f64().unwrap(),
+            std::f64::consts::PI
+        );
+        assert_approx_eq!(
+            features[1]["properties"]["bearing"].as_f64().unwrap(),
+            270.0
+        );
+    }
+
+    #[test]
+    fn test_geojson_write_invalid_tolerance() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(0.0, 3.0), Vector::new(-1.0, 0.0));
+        let path = Path::create(1.0, &vec![start, finish]).unwrap();
+        for tolerance in [0.0, -0.1, f64::NAN] {
+            assert!(matches!(
+                path.to_geojson(tolerance),
+                Err(GeoJsonError::InvalidTolerance)
+            ));
+        }
+    }
+}
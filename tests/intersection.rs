@@ -2,6 +2,8 @@
 mod tests {
     use assert_approx_eq::assert_approx_eq;
     use geomety::geometry2d::*;
+    use sity::*;
+    use std::f64::consts::PI;
 
     #[test]
     fn intersection_line_0() {
@@ -107,4 +109,141 @@ mod tests {
         assert_approx_eq!(pt.x, 0.0_f64, 1e-10);
         assert_approx_eq!(pt.y, 0.0_f64, 1e-10);
     }
+
+    fn arc(
+        cx: f64,
+        cy: f64,
+        r: f64,
+        direction: Direction,
+        start: f64,
+        delta: f64,
+    ) -> DirectedArc<f64> {
+        DirectedArc::new(
+            DirectedCircle::new(Circle::new(Point::new(cx, cy), r), direction),
+            Radian::new(start),
+            Radian::new(delta),
+        )
+    }
+
+    #[test]
+    fn intersection_circle_circle() {
+        let c1 = Circle::new(Point::new(0.0, 0.0), 2.0);
+        match c1.intersection(&Circle::new(Point::new(2.0, 0.0), 2.0)) {
+            CircleIntersection::Two(p, q) => {
+                assert_approx_eq!(p.x, 1.0, 1e-10);
+                assert_approx_eq!(q.x, 1.0, 1e-10);
+                assert_approx_eq!(p.y.abs(), 3.0_f64.sqrt(), 1e-10);
+                assert_approx_eq!(p.y, -q.y, 1e-10);
+            }
+            other => panic!("{:?}", other),
+        }
+        match c1.intersection(&Circle::new(Point::new(3.0, 0.0), 1.0)) {
+            CircleIntersection::Tangent(p) => assert_approx_eq!(p.x, 2.0, 1e-10),
+            other => panic!("{:?}", other),
+        }
+        match c1.intersection(&Circle::new(Point::new(1.0, 0.0), 1.0)) {
+            CircleIntersection::Tangent(p) => assert_approx_eq!(p.x, 2.0, 1e-10),
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(c1.intersection(&c1.clone()), CircleIntersection::Coincident);
+        assert_eq!(
+            c1.intersection(&Circle::new(Point::new(0.5, 0.0), 0.5)),
+            CircleIntersection::None
+        );
+    }
+
+    #[test]
+    fn intersection_line_segment_circle() {
+        let circle = Circle::new(Point::new(0.0, 0.0), 1.0);
+        let line = Line::new(Point::new(-5.0, 1.0), Vector::new(1.0, 0.0));
+        match line.intersection_to_circle(&circle) {
+            CircleIntersection::Tangent(p) => {
+                assert_approx_eq!(p.x, 0.0, 1e-10);
+                assert_approx_eq!(p.y, 1.0, 1e-10);
+            }
+            other => panic!("{:?}", other),
+        }
+        let seg = Segment::new(Point::new(0.0, 0.0), Point::new(5.0, 0.0));
+        match seg.intersection_to_circle(&circle) {
+            CircleIntersection::One(p) => assert_approx_eq!(p.x, 1.0, 1e-10),
+            other => panic!("{:?}", other),
+        }
+        let seg = Segment::new(Point::new(5.0, 0.5), Point::new(-5.0, 0.5));
+        match seg.intersection_to_circle(&circle) {
+            CircleIntersection::Two(p, q) => {
+                assert_approx_eq!(p.x, 0.75_f64.sqrt(), 1e-10);
+                assert_approx_eq!(q.x, -(0.75_f64.sqrt()), 1e-10);
+            }
+            other => panic!("{:?}", other),
+        }
+        let seg = Segment::new(Point::new(2.0, 0.0), Point::new(5.0, 0.0));
+        assert_eq!(
+            seg.intersection_to_circle(&circle),
+            CircleIntersection::None
+        );
+    }
+
+    #[test]
+    fn intersection_segment_arc() {
+        // Upper half of the unit circle, counter-clockwise
+        let upper = arc(0.0, 0.0, 1.0, Direction::CounterClockWise, 0.0, PI);
+        let seg = Segment::new(Point::new(0.5, -2.0), Point::new(0.5, 2.0));
+        match seg.intersection_to_arc(&upper) {
+            CircleIntersection::One(p) => assert_approx_eq!(p.y, 0.75_f64.sqrt(), 1e-10),
+            other => panic!("{:?}", other),
+        }
+        // The same half, described clockwise from the left end
+        let upper = arc(0.0, 0.0, 1.0, Direction::ClockWise, PI, PI);
+        assert!(matches!(
+            seg.intersection_to_arc(&upper),
+            CircleIntersection::One(_)
+        ));
+        let seg = Segment::new(Point::new(-2.0, -1.0), Point::new(2.0, -1.0));
+        assert_eq!(seg.intersection_to_arc(&upper), CircleIntersection::None);
+    }
+
+    #[test]
+    fn intersection_arc_arc() {
+        let upper = arc(0.0, 0.0, 1.0, Direction::CounterClockWise, 0.0, PI);
+        let other = arc(1.0, 0.0, 1.0, Direction::ClockWise, PI, PI);
+        match upper.intersection(&other) {
+            CircleIntersection::One(p) => {
+                assert_approx_eq!(p.x, 0.5, 1e-10);
+                assert_approx_eq!(p.y, 0.75_f64.sqrt(), 1e-10);
+            }
+            other => panic!("{:?}", other),
+        }
+
+        // Same circle: the quarter from PI / 2 to PI is shared
+        let left = arc(0.0, 0.0, 1.0, Direction::CounterClockWise, PI / 2.0, PI);
+        match upper.intersection(&left) {
+            CircleIntersection::Overlap(arcs) => {
+                assert_eq!(arcs.len(), 1);
+                assert_approx_eq!(arcs[0].start_angle().value(), PI / 2.0, 1e-10);
+                assert_approx_eq!(arcs[0].delta_angle().value(), PI / 2.0, 1e-10);
+            }
+            other => panic!("{:?}", other),
+        }
+        // Same circle, only touching at the end points
+        let lower = arc(0.0, 0.0, 1.0, Direction::CounterClockWise, PI, PI / 2.0);
+        assert!(matches!(
+            upper.intersection(&lower),
+            CircleIntersection::One(_)
+        ));
+        let lower = arc(0.0, 0.0, 1.0, Direction::CounterClockWise, PI, PI);
+        assert!(matches!(
+            upper.intersection(&lower),
+            CircleIntersection::Two(_, _)
+        ));
+        // Touching at one end and sharing the quarter from 0 to PI / 2: no empty piece
+        let around = arc(0.0, 0.0, 1.0, Direction::CounterClockWise, PI, 1.5 * PI);
+        match upper.intersection(&around) {
+            CircleIntersection::Overlap(arcs) => {
+                assert_eq!(arcs.len(), 1);
+                assert_approx_eq!(arcs[0].start_angle().value(), 0.0, 1e-10);
+                assert_approx_eq!(arcs[0].delta_angle().value(), PI / 2.0, 1e-10);
+            }
+            other => panic!("{:?}", other),
+        }
+    }
 }
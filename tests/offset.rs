@@ -0,0 +1,124 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::*;
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+
+    const MITRE: Corner<f64> = Corner::Mitre { limit: 2.0 };
+
+    #[test]
+    fn test_offset_mitre() {
+        let square = rectangle(0.0, 0.0, 2.0, 2.0);
+        let grown = square.offset(1.0, MITRE).unwrap();
+        assert_eq!(grown.len(), 1);
+        assert_eq!(grown.polygons()[0].outer().points().len(), 4);
+        assert_approx_eq!(grown.area(), 16.0);
+
+        let shrunk = rectangle(0.0, 0.0, 4.0, 4.0).offset(-1.0, MITRE).unwrap();
+        assert_eq!(shrunk.len(), 1);
+        assert_eq!(shrunk.polygons()[0].outer().points().len(), 4);
+        assert_approx_eq!(shrunk.area(), 4.0);
+
+        // Beyond the limit, corners are bevelled
+        let bevelled = square.offset(1.0, Corner::Mitre { limit: 1.2 }).unwrap();
+        assert_eq!(bevelled.polygons()[0].outer().points().len(), 8);
+        assert_approx_eq!(bevelled.area(), 14.0);
+    }
+
+    #[test]
+    fn test_offset_round() {
+        let square = rectangle(0.0, 0.0, 2.0, 2.0);
+        let grown = square
+            .offset(1.0, Corner::Round { tolerance: 0.01 })
+            .unwrap();
+        assert_eq!(grown.len(), 1);
+        let exact = 4.0 + 8.0 + std::f64::consts::PI;
+        assert!(grown.area() >= exact);
+        assert!(grown.area() <= exact * 1.01);
+        assert!(grown.contains(&Point::new(2.69, 2.69)));
+        assert!(!grown.contains(&Point::new(2.75, 2.75)));
+
+        // One side per corner: a quarter turn gives the mitred square
+        let coarse = square
+            .offset(1.0, Corner::Round { tolerance: 0.5 })
+            .unwrap();
+        assert_eq!(coarse.polygons()[0].outer().points().len(), 4);
+        assert_approx_eq!(coarse.area(), 16.0);
+
+        // Shrinking a convex polygon keeps sharp corners
+        let round = Corner::Round { tolerance: 0.01 };
+        let shrunk = rectangle(0.0, 0.0, 4.0, 4.0).offset(-1.0, round).unwrap();
+        assert_eq!(shrunk.polygons()[0].outer().points().len(), 4);
+        assert_approx_eq!(shrunk.area(), 4.0);
+    }
+
+    #[test]
+    fn test_offset_round_tolerance() {
+        // The corners stay between the exact arcs and `tolerance` outside them
+        let square = rectangle(0.0, 0.0, 2.0, 2.0);
+        let mut vertices = 0;
+        for tolerance in [0.1, 0.01, 0.001] {
+            let grown = square.offset(1.0, Corner::Round { tolerance }).unwrap();
+            let outer = grown.polygons()[0].outer();
+            for p in outer.points() {
+                let d = p.distance(&square);
+                assert!(
+                    d >= 1.0 - 1e-9 && d <= 1.0 + tolerance,
+                    "{} is {} away",
+                    p,
+                    d
+                );
+            }
+            assert!(outer.points().len() > vertices);
+            vertices = outer.points().len();
+        }
+    }
+
+    #[test]
+    fn test_offset_round_invalid_tolerance() {
+        let square = rectangle(0.0, 0.0, 2.0, 2.0);
+        for tolerance in [0.0, -0.1, f64::NAN] {
+            assert_eq!(
+                square.offset(1.0, Corner::Round { tolerance }),
+                Err(OffsetError::InvalidTolerance)
+            );
+        }
+    }
+
+    #[test]
+    fn test_offset_concave() {
+        // Shrinking splits the U in its two arms
+        let shrunk = u_shape().offset(-0.6, MITRE).unwrap();
+        assert_eq!(shrunk.len(), 2);
+        assert_approx_eq!(shrunk.area(), 2.0 * 0.8 * 1.8);
+
+        // Growing closes the notch: the overlapping parts are merged
+        let grown = u_shape().offset(0.6, MITRE).unwrap();
+        assert_eq!(grown.len(), 1);
+        assert!(grown.polygons()[0].holes().is_empty());
+        assert_eq!(grown.polygons()[0].outer().points().len(), 4);
+        assert_approx_eq!(grown.area(), 6.2 * 4.2);
+    }
+
+    #[test]
+    fn test_offset_holes() {
+        let field = PolygonWithHoles::new(
+            rectangle(0.0, 0.0, 10.0, 10.0),
+            vec![rectangle(4.0, 4.0, 6.0, 6.0)],
+        );
+        let grown = field.offset(0.5, MITRE).unwrap();
+        assert_eq!(grown.len(), 1);
+        assert_eq!(grown.polygons()[0].holes().len(), 1);
+        assert_approx_eq!(grown.area(), 11.0 * 11.0 - 1.0);
+
+        let shrunk = field.offset(-0.5, MITRE).unwrap();
+        assert_approx_eq!(shrunk.area(), 9.0 * 9.0 - 9.0);
+
+        // The hole disappears
+        assert!(field.offset(1.5, MITRE).unwrap().polygons()[0]
+            .holes()
+            .is_empty());
+    }
+}
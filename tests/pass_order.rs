@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use geomety::geometry2d::*;
+
+    fn field() -> Polygon<f64> {
+        Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(20.0, 0.0),
+            Point::new(20.0, 10.0),
+            Point::new(0.0, 10.0),
+        ])
+        .unwrap()
+    }
+
+    /// Returns the indices of the passes of a mapping along x, `distance` apart, in driving order.
+    fn pass_order(path: &Path<f64>, distance: f64) -> Vec<usize> {
+        // Each pass is a straight trajectory, after the one reaching it
+        let heights = path
+            .trajectories()
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .map(|traj| {
+                let seg = traj.segment().unwrap();
+                assert!((seg.first().y - seg.second().y).abs() < 1e-9);
+                seg.first().y
+            })
+            .collect::<Vec<_>>();
+        let lowest = heights.iter().cloned().fold(f64::INFINITY, f64::min);
+        heights
+            .iter()
+            .map(|y| ((y - lowest) / distance).round() as usize)
+            .collect()
+    }
+
+    #[test]
+    fn test_mapping_skip_order() {
+        // Every pass once, from the first one, never right after a neighbour
+        let start = Pose::new(Point::new(0.0, -1.0), Vector::new(1.0, 0.0));
+        let direction = Vector::new(1.0, 0.0);
+        for count in 5..40 {
+            let field = Polygon::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(20.0, 0.0),
+                Point::new(20.0, count as f64),
+                Point::new(0.0, count as f64),
+            ])
+            .unwrap();
+            let passes = (0..count).collect::<Vec<usize>>();
+            for n in 1..count {
+                let order = PassOrder::Skip(n);
+                let path =
+                    Path::mapping_with_order(&start, &direction, 2.0, 1.0, &field, order).unwrap();
+                let ordered = pass_order(&path, 1.0);
+                if n == 1 {
+                    assert_eq!(ordered, passes);
+                    continue;
+                }
+                assert_eq!(ordered[0], 0);
+                let mut sorted = ordered.clone();
+                sorted.sort();
+                assert_eq!(sorted, passes);
+                // The rounds {0, 4}, {1}, {2}, {3} of five passes leave no such order
+                if (count, n) != (5, 4) {
+                    assert!(
+                        ordered.windows(2).all(|w| w[0].abs_diff(w[1]) >= 2),
+                        "{:?}",
+                        ordered
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mapping_with_order() {
+        let field = field();
+        let start = Pose::new(Point::new(0.0, -1.0), Vector::new(1.0, 0.0));
+        let direction = Vector::new(1.0, 0.0);
+        let (radius, distance) = (2.0, 1.0);
+
+        let sequential = Path::mapping(&start, &direction, radius, distance, &field).unwrap();
+        let same = Path::mapping_with_order(
+            &start,
+            &direction,
+            radius,
+            distance,
+            &field,
+            PassOrder::Sequential,
+        )
+        .unwrap();
+        assert_eq!(sequential, same);
+
+        let skip = Path::mapping_with_order(
+            &start,
+            &direction,
+            radius,
+            distance,
+            &field,
+            PassOrder::Skip(4),
+        )
+        .unwrap();
+        assert!(skip.length() < sequential.length());
+
+        let shortest = Path::mapping_with_order(
+            &start,
+            &direction,
+            radius,
+            distance,
+            &field,
+            PassOrder::Shortest,
+        )
+        .unwrap();
+        assert!(shortest.length() <= skip.length());
+        assert_eq!(
+            shortest.trajectories().len(),
+            sequential.trajectories().len()
+        );
+    }
+}
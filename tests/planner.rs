@@ -0,0 +1,75 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::*;
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+
+    fn assert_clear(path: &Path<f64>, obstacles: &[Obstacle<f64>]) {
+        let length = path.length();
+        let mut s = 0.0;
+        while s < length {
+            let pose = path.follow(1.0, s).pose;
+            assert!(obstacles.iter().all(|o| !o.contains(pose.position())));
+            s += 0.05;
+        }
+    }
+
+    #[test]
+    fn test_plan_free() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let goal = Pose::new(Point::new(10.0, 0.0), Vector::new(1.0, 0.0));
+        let path = Path::plan(&start, &goal, 1.0, &[], 2.0, 8).unwrap();
+        assert_approx_eq!(path.length(), 10.0, 1e-9);
+        assert_eq!(path.trajectories().len(), 1);
+    }
+
+    #[test]
+    fn test_plan_around_obstacles() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let goal = Pose::new(Point::new(20.0, 0.0), Vector::new(1.0, 0.0));
+        let obstacles = vec![
+            Obstacle::from(square(10.0, 0.0, 2.0)),
+            Obstacle::from(Circle::new(Point::new(15.0, 4.0), 1.5)),
+        ];
+        let path = Path::plan(&start, &goal, 1.0, &obstacles, 2.0, 8).unwrap();
+        assert!(path.length() > 20.0);
+        let last = path.trajectories().last().unwrap().finish();
+        assert_approx_eq!(last.position().x, 20.0, 1e-9);
+        assert_approx_eq!(last.position().y, 0.0, 1e-9);
+        assert_clear(&path, &obstacles);
+    }
+
+    #[test]
+    fn test_plan_blocked_goal() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let goal = Pose::new(Point::new(10.0, 0.0), Vector::new(1.0, 0.0));
+        let obstacles = vec![Obstacle::from(square(10.0, 0.0, 1.0))];
+        assert_eq!(
+            Path::plan(&start, &goal, 1.0, &obstacles, 2.0, 8),
+            Err(PlanError::Blocked)
+        );
+        // The arguments are checked first
+        assert_eq!(
+            Path::plan(&start, &goal, 1.0, &obstacles, 0.0, 8),
+            Err(PlanError::InvalidSpacing)
+        );
+    }
+
+    #[test]
+    fn test_plan_lattice_size() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let goal = Pose::new(Point::new(10.0, 0.0), Vector::new(1.0, 0.0));
+        for spacing in [0.0, -1.0, f64::NAN] {
+            assert_eq!(
+                Path::plan(&start, &goal, 1.0, &[], spacing, 8),
+                Err(PlanError::InvalidSpacing)
+            );
+        }
+        assert!(matches!(
+            Path::plan(&start, &goal, 1.0, &[], 0.01, 8),
+            Err(PlanError::TooManyPoses(n)) if n > MAX_LATTICE_POSES
+        ));
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use geomety::geometry2d::plot::Chart;
+    use geomety::geometry2d::*;
+    use plotters::coord::Shift;
+    use plotters::prelude::{
+        BitMapBackend, ChartBuilder, Color, DrawingArea, DrawingBackend, IntoDrawingArea,
+        SVGBackend, BLACK, BLUE, GREEN, RED, WHITE,
+    };
+
+    fn draw_all<DB: DrawingBackend>(chart: &mut Chart<'_, DB>) {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(0.0, 3.0), Vector::new(-1.0, 0.0));
+        let path = Path::create(1.0, &vec![start.clone(), finish]).unwrap();
+        let polygon = Polygon::new(vec![
+            Point::new(-1.0, -1.0),
+            Point::new(3.0, -1.0),
+            Point::new(3.0, 4.0),
+        ])
+        .unwrap();
+
+        Point::new(1.0, 1.0).draw(chart, BLACK, 3);
+        Circle::new(Point::new(1.0, 1.0), 0.5).draw(chart, BLACK);
+        Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0)).draw(chart, RED);
+        polygon.draw(chart, BLACK);
+        start.draw(chart, GREEN, 3, GREEN, 0.5);
+        path.trajectories()[0].draw(chart, BLUE);
+        path.trajectories()[0].start_rotation().draw(chart, BLUE);
+        path.draw(chart, BLUE.stroke_width(2));
+    }
+
+    fn chart<DB: DrawingBackend>(root: &DrawingArea<DB, Shift>) -> Chart<'_, DB> {
+        root.fill(&WHITE).unwrap();
+        ChartBuilder::on(root)
+            .build_cartesian_2d(-2.0..4.0, -2.0..5.0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_plot_svg() {
+        let mut svg = String::new();
+        {
+            let root = SVGBackend::with_string(&mut svg, (200, 200)).into_drawing_area();
+            draw_all(&mut chart(&root));
+            root.present().unwrap();
+        }
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("<polyline"));
+        assert!(svg.contains("<circle"));
+    }
+
+    #[test]
+    fn test_plot_bitmap_buffer() {
+        let mut buffer = vec![0; 200 * 200 * 3];
+        {
+            let root = BitMapBackend::with_buffer(&mut buffer, (200, 200)).into_drawing_area();
+            draw_all(&mut chart(&root));
+            root.present().unwrap();
+        }
+        // Some pixels are neither white nor untouched
+        assert!(buffer
+            .chunks(3)
+            .any(|p| p != [255, 255, 255] && p != [0, 0, 0]));
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use geomety::geometry2d::*;
+
+    fn points(list: &[(f64, f64)]) -> Vec<Point<f64>> {
+        list.iter().map(|(x, y)| Point::new(*x, *y)).collect()
+    }
+
+    #[test]
+    fn test_polygon_new_concave() {
+        // L-shaped field, given clockwise
+        let list = points(&[
+            (0.0, 0.0),
+            (0.0, 2.0),
+            (1.0, 2.0),
+            (1.0, 1.0),
+            (2.0, 1.0),
+            (2.0, 0.0),
+        ]);
+        let polygon = Polygon::new(list).unwrap();
+        assert_eq!(polygon.winding(), Orientation::CounterClockWise);
+        assert_eq!(polygon.points().len(), 6);
+        assert_eq!(polygon.points()[0], Point::new(0.0, 0.0));
+        assert_eq!(polygon.points()[1], Point::new(2.0, 0.0));
+        assert!(!polygon.is_convex());
+
+        let clockwise = polygon.with_winding(Orientation::ClockWise);
+        assert_eq!(clockwise.winding(), Orientation::ClockWise);
+        assert_eq!(clockwise.points()[1], Point::new(0.0, 2.0));
+
+        // The area does not depend on the winding
+        assert_eq!(polygon.area(), 3.0);
+        assert_eq!(clockwise.area(), 3.0);
+        let square = Polygon::new(points(&[(0.0, 0.0), (3.0, 0.0), (3.0, 3.0), (0.0, 3.0)]));
+        let field = PolygonWithHoles::new(square.unwrap(), vec![polygon]);
+        assert_eq!(field.area(), 6.0);
+        assert_eq!(MultiPolygon::new(vec![field.clone(), field]).area(), 12.0);
+    }
+
+    #[test]
+    fn test_polygon_is_convex() {
+        let list = points(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+        assert!(Polygon::new(list).unwrap().is_convex());
+    }
+
+    #[test]
+    fn test_polygon_new_errors() {
+        assert_eq!(
+            Polygon::new(points(&[(0.0, 0.0), (1.0, 0.0)])),
+            Err(PolygonError::TooFewVertices(2))
+        );
+        assert_eq!(
+            Polygon::new(points(&[(0.0, 0.0), (1.0, 0.0), (1.0, 0.0), (0.0, 1.0)])),
+            Err(PolygonError::DuplicateVertex(1))
+        );
+        assert_eq!(
+            Polygon::new(points(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (0.0, 0.0)])),
+            Err(PolygonError::DuplicateVertex(3))
+        );
+        assert_eq!(
+            Polygon::new(points(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1e-17)])),
+            Err(PolygonError::ZeroArea)
+        );
+        // Collinear vertices: the closing edge runs back over the first one
+        assert_eq!(
+            Polygon::new(points(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)])),
+            Err(PolygonError::SelfIntersection(0, 2))
+        );
+        // Bow tie, with as much area on both sides
+        assert_eq!(
+            Polygon::new(points(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)])),
+            Err(PolygonError::SelfIntersection(0, 2))
+        );
+        assert_eq!(
+            Polygon::new(points(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 3.0)])),
+            Err(PolygonError::SelfIntersection(0, 2))
+        );
+        // Spike folding back onto the previous edge
+        assert_eq!(
+            Polygon::new(points(&[
+                (0.0, 0.0),
+                (2.0, 0.0),
+                (2.0, 2.0),
+                (2.0, 1.0),
+                (0.0, 1.0)
+            ])),
+            Err(PolygonError::SelfIntersection(1, 2))
+        );
+    }
+}
@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+
+    fn square(x0: f64, y0: f64, size: f64) -> Polygon<f64> {
+        Polygon::new(vec![
+            Point::new(x0, y0),
+            Point::new(x0 + size, y0),
+            Point::new(x0 + size, y0 + size),
+            Point::new(x0, y0 + size),
+        ])
+        .unwrap()
+    }
+
+    fn field() -> PolygonWithHoles<f64> {
+        PolygonWithHoles::new(square(0.0, 0.0, 10.0), vec![square(4.0, 4.0, 2.0)])
+    }
+
+    #[test]
+    fn test_polygon_with_holes_contains() {
+        let field = field();
+        assert_eq!(field.holes()[0].winding(), Orientation::ClockWise);
+        assert!(field.contains(&Point::new(1.0, 1.0)));
+        assert!(!field.contains(&Point::new(5.0, 5.0)));
+        assert!(field.contains(&Point::new(4.0, 5.0)));
+        assert!(!field.contains(&Point::new(11.0, 5.0)));
+        assert_eq!(
+            field.classify(&Point::new(3.999, 5.0), 0.01),
+            PointPosition::OnBoundary
+        );
+        assert_eq!(
+            field.classify(&Point::new(5.0, 5.0), 0.01),
+            PointPosition::Outside
+        );
+    }
+
+    #[test]
+    fn test_polygon_with_holes_from() {
+        let hole = field().holes()[0].clone();
+        let polygon = PolygonWithHoles::from(hole.clone());
+        assert_eq!(polygon.outer().winding(), Orientation::CounterClockWise);
+        assert_eq!(polygon.outer().points()[0], hole.points()[0]);
+        assert!(polygon.contains(&Point::new(5.0, 5.0)));
+    }
+
+    #[test]
+    fn test_polygon_with_holes_distance() {
+        let field = field();
+        assert_approx_eq!(Point::new(3.0, 5.0).distance(&field), 1.0);
+        assert_approx_eq!(Point::new(5.0, 5.0).distance(&field), 1.0);
+        assert_approx_eq!(Point::new(0.5, 5.0).distance(&field), 0.5);
+    }
+
+    #[test]
+    fn test_line_intersection_to_polygon_with_holes() {
+        let line = Line::new(Point::new(0.0, 5.0), Vector::new(-1.0, 0.0));
+        let points = line.intersection_to_polygon_with_holes(&field());
+        let expected = vec![
+            Point::new(10.0, 5.0),
+            Point::new(6.0, 5.0),
+            Point::new(4.0, 5.0),
+            Point::new(0.0, 5.0),
+        ];
+        assert_eq!(points, expected);
+    }
+
+    #[test]
+    fn test_polygon_with_holes_mapping() {
+        let field = field();
+        let segments = field.mapping(&Vector::new(1.0, 0.0), 0.7);
+        assert!(!segments.is_empty());
+        let mut split = 0;
+        for seg in segments.iter() {
+            let y = seg.first().y;
+            if y > 4.0 && y < 6.0 {
+                split += 1;
+            }
+            for t in [0.1, 0.5, 0.9] {
+                let p = Point::new(
+                    seg.first().x + (seg.second().x - seg.first().x) * t,
+                    seg.first().y + (seg.second().y - seg.first().y) * t,
+                );
+                assert!(field.contains(&p));
+            }
+        }
+        // the passes crossing the hole are split in two
+        assert_eq!(split, 2 * 3);
+
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let path = Path::optimal_mapping(&start, 1.0, 1.0, &field);
+        assert!(path.is_some());
+    }
+
+    #[test]
+    fn test_polygon_with_holes_mapping_cells() {
+        // The passes keep the clearance away from the hole, if any
+        let field = field();
+        let hole = &field.holes()[0];
+        for clearance in [0.0, 1.0] {
+            let cells = field.mapping_cells(&Vector::new(1.0, 0.0), 0.5, clearance);
+            assert!(cells.len() > 1);
+            for seg in cells.iter().flatten() {
+                for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+                    let p = Point::new(
+                        seg.first().x + (seg.second().x - seg.first().x) * t,
+                        seg.first().y + (seg.second().y - seg.first().y) * t,
+                    );
+                    assert!(!hole.contains(&p) || p.distance(hole) < 1e-9);
+                    assert!(p.distance(hole) >= clearance - 1e-9, "{} is too close", p);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_polygon_with_holes_path_mapping() {
+        // Never in the hole: neither between the pieces of a pass nor when turning at their ends
+        let field = field();
+        let hole = &field.holes()[0];
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        for direction in [Vector::new(1.0, 0.0), Vector::new(1.0, 2.0)] {
+            let path = Path::mapping(&start, &direction, 0.5, 1.0, &field).unwrap();
+            let mut time = 0.0;
+            while time < path.length() {
+                let position = path.follow(1.0, time).pose.position().clone();
+                assert_ne!(
+                    hole.classify(&position, 1e-9),
+                    PointPosition::Inside,
+                    "{} is in the hole",
+                    position
+                );
+                time += 0.01;
+            }
+        }
+    }
+
+    #[test]
+    fn test_concave_polygon_mapping() {
+        // U shape: the passes crossing the notch are split
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ])
+        .unwrap();
+        let segments = polygon.mapping(&Vector::new(1.0, 0.0), 0.5);
+        for seg in segments.iter() {
+            let middle = Point::new(
+                (seg.first().x + seg.second().x) / 2.0,
+                (seg.first().y + seg.second().y) / 2.0,
+            );
+            assert!(polygon.contains(&middle));
+        }
+        assert!(segments.iter().any(|seg| seg.first().y > 1.0));
+    }
+}
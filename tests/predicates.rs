@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use geomety::geometry2d::*;
+
+    #[test]
+    fn test_orientation_exact() {
+        // Points a few ulps around the line y = x
+        let exact = Predicates::Exact;
+        let (q, r) = (Point::new(12.0, 12.0), Point::new(24.0, 24.0));
+        let ulp = f64::EPSILON / 2.0;
+        for i in 0..32 {
+            for j in 0..32 {
+                let p = Point::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+                let expected = match j.cmp(&i) {
+                    std::cmp::Ordering::Greater => Orientation::CounterClockWise,
+                    std::cmp::Ordering::Less => Orientation::ClockWise,
+                    std::cmp::Ordering::Equal => Orientation::Collinear,
+                };
+                assert_eq!(exact.orientation(&p, &q, &r), expected);
+                assert_eq!(exact.orientation(&q, &r, &p), expected);
+            }
+        }
+
+        let nan = Point::new(f64::NAN, 0.0);
+        assert_eq!(exact.orientation(&nan, &q, &r), Orientation::Collinear);
+        assert_eq!(points_orientation(&nan, &q, &r), Orientation::Collinear);
+    }
+
+    #[test]
+    fn test_orientation_epsilon() {
+        let (a, b) = (Point::new(0.0, 0.0), Point::new(10.0, 0.0));
+        let c = Point::new(5.0, 1e-7);
+        assert_eq!(
+            Predicates::Exact.orientation(&a, &b, &c),
+            Orientation::CounterClockWise
+        );
+        assert_eq!(
+            Predicates::Epsilon(1e-6).orientation(&a, &b, &c),
+            Orientation::Collinear
+        );
+        assert_eq!(
+            Predicates::Epsilon(1e-8).orientation(&a, &b, &c),
+            Orientation::CounterClockWise
+        );
+    }
+
+    #[test]
+    fn test_incircle() {
+        let (a, b, c) = (
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(-1.0, 0.0),
+        );
+        let exact = Predicates::Exact;
+        let on = Point::new(0.0, -1.0);
+        let inside = Point::new(0.0, -0.9999999999999999);
+        let outside = Point::new(0.0, -1.0000000000000002);
+        assert_eq!(exact.incircle(&a, &b, &c, &on), PointPosition::OnBoundary);
+        assert_eq!(exact.incircle(&a, &b, &c, &inside), PointPosition::Inside);
+        assert_eq!(exact.incircle(&c, &b, &a, &inside), PointPosition::Inside);
+        assert_eq!(exact.incircle(&a, &b, &c, &outside), PointPosition::Outside);
+
+        let epsilon = Predicates::Epsilon(1e-9);
+        assert_eq!(
+            epsilon.incircle(&a, &b, &c, &inside),
+            PointPosition::OnBoundary
+        );
+        assert_eq!(
+            epsilon.incircle(&c, &b, &a, &Point::new(0.0, 0.5)),
+            PointPosition::Inside
+        );
+        assert_eq!(
+            epsilon.incircle(&a, &b, &c, &Point::new(3.0, 0.0)),
+            PointPosition::Outside
+        );
+    }
+
+    #[test]
+    fn test_switchable_algorithms() {
+        let u = Vector::new(1.0, 0.0);
+        let v = Vector::new(1e6, 1e-7);
+        assert!(!u.is_parallel_with(&v, &Predicates::Exact));
+        assert!(u.is_parallel_with(&v, &Predicates::Epsilon(1e-6)));
+        let s = Segment::new(Point::new(0.0, 1.0), Point::new(2.0, 1.0 + 1e-12));
+        let l = Line::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        assert!(!s.is_parallel_with(&l, &Predicates::Exact));
+        assert!(s.is_parallel_with(&l, &Predicates::Epsilon(1e-9)));
+
+        let square = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ])
+        .unwrap();
+        let near = Point::new(1.0 + 1e-12, 0.5);
+        assert!(!square.contains_with(&near, &Predicates::Exact));
+        assert!(square.contains_with(&near, &Predicates::Epsilon(1e-9)));
+        assert!(square.contains_with(&Point::new(1.0, 0.5), &Predicates::Exact));
+
+        // Nearly collinear points on an edge are dropped with a tolerance
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 2.0 + 1e-12),
+            Point::new(0.0, 2.0),
+        ];
+        let hull = Polygon::convex_hull_with(points.clone(), &Predicates::Exact).unwrap();
+        assert_eq!(hull.points().len(), 5);
+        let hull = Polygon::convex_hull_with(points, &Predicates::Epsilon(1e-9)).unwrap();
+        assert_eq!(hull.points().len(), 4);
+
+        // A vertex slightly pushed in
+        let dented = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 2.0 - 1e-12),
+            Point::new(0.0, 2.0),
+        ])
+        .unwrap();
+        assert!(!dented.is_convex_with(&Predicates::Exact));
+        assert!(dented.is_convex_with(&Predicates::Epsilon(1e-9)));
+
+        let field = PolygonWithHoles::new(
+            Polygon::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(4.0, 0.0),
+                Point::new(4.0, 4.0),
+                Point::new(0.0, 4.0),
+            ])
+            .unwrap(),
+            vec![Polygon::new(vec![
+                Point::new(1.5, 1.5),
+                Point::new(2.5, 1.5),
+                Point::new(2.5, 2.5),
+                Point::new(1.5, 2.5),
+            ])
+            .unwrap()],
+        );
+        let near = Point::new(2.0, 1.5 + 1e-12);
+        assert!(!field.contains_with(&near, &Predicates::Exact));
+        assert!(field.contains_with(&near, &Predicates::Epsilon(1e-9)));
+        assert!(field.contains_with(&Point::new(2.0, 1.5), &Predicates::Exact));
+        assert!(!field.contains_with(&Point::new(2.0, 2.0), &Predicates::Epsilon(1e-9)));
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+
+    fn assert_point_eq(p1: &Point<f64>, p2: &Point<f64>) {
+        assert_approx_eq!(p1.x, p2.x, 1e-9);
+        assert_approx_eq!(p1.y, p2.y, 1e-9);
+    }
+
+    fn end_point(element: &ReedsSheppElement<f64>) -> Point<f64> {
+        match element.motion() {
+            Transition::Segment(seg) => seg.second().clone(),
+            Transition::Arc(arc) => arc.finish_point(),
+        }
+    }
+
+    fn start_point(element: &ReedsSheppElement<f64>) -> Point<f64> {
+        match element.motion() {
+            Transition::Segment(seg) => seg.first().clone(),
+            Transition::Arc(arc) => arc.start_point(),
+        }
+    }
+
+    fn check(start: &Pose<f64>, finish: &Pose<f64>, radius: f64) {
+        let all = ReedsShepp::create_all(start, finish, radius);
+        assert!(!all.is_empty());
+        for traj in all.iter() {
+            let mut point = start.position().clone();
+            for element in traj.elements().iter() {
+                assert_point_eq(&start_point(element), &point);
+                point = end_point(element);
+            }
+            assert_point_eq(&point, finish.position());
+        }
+    }
+
+    #[test]
+    fn test_reeds_shepp_continuity() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        check(
+            &start,
+            &Pose::new(Point::new(5.0, 3.0), Vector::new(0.0, 1.0)),
+            1.0,
+        );
+        check(
+            &start,
+            &Pose::new(Point::new(-2.0, 1.0), Vector::new(1.0, 1.0)),
+            1.0,
+        );
+        check(
+            &start,
+            &Pose::new(Point::new(0.5, -0.5), Vector::new(-1.0, 0.0)),
+            2.0,
+        );
+    }
+
+    #[test]
+    fn test_reeds_shepp_straight_backward() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(-3.0, 0.0), Vector::new(1.0, 0.0));
+        let traj = ReedsShepp::create(&start, &finish, 1.0).unwrap();
+        assert_approx_eq!(traj.length(), 3.0, 1e-9);
+        assert_eq!(traj.elements().len(), 1);
+        assert_eq!(traj.elements()[0].gear(), Gear::Backward);
+    }
+
+    #[test]
+    fn test_reeds_shepp_shorter_than_dubins() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(0.0, 1.0), Vector::new(1.0, 0.0));
+        let rs = ReedsShepp::create(&start, &finish, 1.0).unwrap();
+        let dubins = Trajectory::create(&start, &finish, 1.0).unwrap();
+        assert!(rs.length() < dubins.length());
+    }
+
+    #[test]
+    fn test_reeds_shepp_follow_backward() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(-3.0, 0.0), Vector::new(1.0, 0.0));
+        let traj = ReedsShepp::create(&start, &finish, 1.0).unwrap();
+        let res = traj.follow(1.0, 1.0);
+        assert_eq!(res.complete, None);
+        assert_point_eq(res.pose.position(), &Point::new(-1.0, 0.0));
+        assert_approx_eq!(res.pose.orientation().dx, 1.0, 1e-9);
+        let res = traj.follow(1.0, 5.0);
+        assert_approx_eq!(res.complete.unwrap(), 3.0, 1e-9);
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+    use plotters::prelude::{IntoDrawingArea, SVGBackend, BLACK, BLUE, RED};
+    use sity::*;
+
+    fn scene() -> Scene<f64> {
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 2.0),
+            Point::new(0.0, 2.0),
+        ])
+        .unwrap();
+        let start = Pose::new(Point::new(1.0, 1.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(3.0, 1.0), Vector::new(1.0, 0.0));
+        let path = Path::create(0.5, &vec![start.clone(), finish]).unwrap();
+        let mut scene = Scene::new();
+        scene
+            .caption("Field")
+            .add_labelled(&polygon, BLACK, "field")
+            .add_labelled(&path, Style::new(BLUE, 3, 4), "path")
+            .add(&start, RED);
+        scene
+    }
+
+    #[test]
+    fn test_scene_view() {
+        assert_eq!(
+            Scene::<f64>::new().view(),
+            Aabb::new(Point::new(-1.0, -1.0), Point::new(1.0, 1.0))
+        );
+
+        // The bounds with 5% of padding, widened to a square chart
+        let mut scene = scene();
+        scene.size(1000, 1000);
+        let view = scene.view();
+        assert!(view.contains(&Aabb::new(Point::new(-0.2, -0.2), Point::new(4.2, 2.2))));
+        assert_approx_eq!(view.min().x, -0.2);
+        assert_approx_eq!(view.max().x, 4.2);
+        assert_approx_eq!((view.min().y + view.max().y) / 2.0, 1.0);
+        let chart = (1000.0 - 70.0, 1000.0 - 110.0);
+        assert_approx_eq!(view.width() / view.height(), chart.0 / chart.1);
+
+        scene.padding(1.0);
+        let view = scene.view();
+        assert_approx_eq!(view.min().x, -1.0);
+    }
+
+    fn render<T: Number + HasValue<Output = f64> + UnitName>(scene: &Scene<T>) -> String {
+        let mut svg = String::new();
+        {
+            let root = SVGBackend::with_string(&mut svg, (600, 400)).into_drawing_area();
+            scene.draw(&root).unwrap();
+        }
+        svg
+    }
+
+    #[test]
+    fn test_scene_render() {
+        let scene = scene();
+        let svg = render(&scene);
+        let texts = svg.lines().map(str::trim).collect::<Vec<_>>();
+        for text in ["Field", "field", "path", "x", "y"] {
+            assert!(texts.contains(&text), "{} is missing", text);
+        }
+
+        let directory = std::env::temp_dir();
+        for file in ["geomety_scene.svg", "geomety_scene.png"] {
+            let file = directory.join(file);
+            scene.save(&file).unwrap();
+            assert!(std::fs::metadata(&file).unwrap().len() > 0);
+            std::fs::remove_file(&file).unwrap();
+        }
+        assert!(matches!(
+            scene.save(directory.join("geomety_scene.pdf")),
+            Err(SceneError::UnknownFormat(_))
+        ));
+    }
+    #[test]
+    fn test_scene_unit() {
+        let segment = Segment::new(
+            Point::new(metre(0.0), metre(0.0)),
+            Point::new(metre(3.0), metre(2.0)),
+        );
+        let mut scene = Scene::new();
+        scene.add(&segment, BLUE);
+        let svg = render(&scene);
+        let texts = svg.lines().map(str::trim).collect::<Vec<_>>();
+        for text in ["x (m)", "y (m)"] {
+            assert!(texts.contains(&text), "{} is missing", text);
+        }
+    }
+}
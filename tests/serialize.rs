@@ -0,0 +1,92 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use geomety::geometry2d::*;
+    use sity::*;
+
+    /// Reads a point with numbers of the same type as `_unit`.
+    fn load<T>(json: &str, _unit: T) -> Result<Point<T>, serde_json::Error>
+    where
+        T: Number,
+        Point<T>: serde::de::DeserializeOwned,
+    {
+        serde_json::from_str(json)
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(1.0, 0.0), Vector::new(-1.0, 0.0));
+        let path = Path::create(1.0, &vec![start.clone(), finish]).unwrap();
+        let json = serde_json::to_string(&path).unwrap();
+        assert_eq!(serde_json::from_str::<Path<f64>>(&json).unwrap(), path);
+
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, 1.0),
+        ])
+        .unwrap();
+        let json = serde_json::to_string(&polygon).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Polygon<f64>>(&json).unwrap(),
+            polygon
+        );
+
+        let poses = vec![start.clone(), start];
+        let json = serde_json::to_string(&poses).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<Pose<f64>>>(&json).unwrap(),
+            poses
+        );
+
+        let line = Line::new(Point::new(1.0, 2.0), Vector::new(0.0, 1.0));
+        let json = serde_json::to_string(&line).unwrap();
+        assert_eq!(serde_json::from_str::<Line<f64>>(&json).unwrap(), line);
+    }
+
+    #[test]
+    fn test_serialize_unit() {
+        let point = Point::new(1.5, -2.0);
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(json, r#"{"unit":"","x":1.5,"y":-2.0}"#);
+
+        let feet = r#"{"unit":"ft","x":1.5,"y":-2.0}"#;
+        let error = serde_json::from_str::<Point<f64>>(feet).unwrap_err();
+        assert!(error.to_string().contains("expected unit ``, found `ft`"));
+
+        let flat =
+            r#"{"unit":"","points":[{"x":0.0,"y":0.0},{"x":1.0,"y":0.0},{"x":2.0,"y":0.0}]}"#;
+        assert!(serde_json::from_str::<Polygon<f64>>(flat).is_err());
+
+        let arc = r#"{"unit":"","center":{"x":0.0,"y":0.0},"radius":2.0,"direction":"ClockWise","start_angle":0.0,"delta_angle":1.0}"#;
+        let arc = serde_json::from_str::<DirectedArc<f64>>(arc).unwrap();
+        assert_eq!(arc.direction(), Direction::ClockWise);
+        assert_eq!(arc.radius(), 2.0);
+    }
+    #[test]
+    fn test_serialize_metre() {
+        let point = Point::new(metre(1.5), metre(-2.0));
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(json, r#"{"unit":"m","x":1.5,"y":-2.0}"#);
+        assert_eq!(load(&json, metre(0.0)).unwrap(), point);
+
+        let start = Pose::new(
+            Point::new(metre(0.0), metre(0.0)),
+            Vector::new(metre(1.0), metre(0.0)),
+        );
+        let finish = Pose::new(
+            Point::new(metre(1.0), metre(0.0)),
+            Vector::new(metre(-1.0), metre(0.0)),
+        );
+        let path = Path::create(metre(1.0), &vec![start, finish]).unwrap();
+        let json = serde_json::to_string(&path).unwrap();
+        assert_eq!(serde_json::from_str::<Path<_>>(&json).unwrap(), path);
+
+        // Metres are neither feet nor plain numbers
+        let feet = r#"{"unit":"ft","x":1.5,"y":-2.0}"#;
+        let error = load(feet, metre(0.0)).unwrap_err();
+        assert!(error.to_string().contains("expected unit `m`, found `ft`"));
+        let error = load(r#"{"unit":"m","x":1.5,"y":-2.0}"#, 0.0).unwrap_err();
+        assert!(error.to_string().contains("expected unit ``, found `m`"));
+    }
+}
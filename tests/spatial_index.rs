@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+    use sity::*;
+
+    /// Short segments scattered over a 20 x 20 square, reproducibly.
+    fn segments() -> Vec<Segment<f64>> {
+        let mut seed = 7_u64;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 11) as f64 / (1_u64 << 53) as f64
+        };
+        (0..500)
+            .map(|_| {
+                let (x, y) = (20.0 * next(), 20.0 * next());
+                let (dx, dy) = (next() - 0.5, next() - 0.5);
+                Segment::new(Point::new(x, y), Point::new(x + dx, y + dy))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_spatial_index_queries() {
+        let segments = segments();
+        let index = SpatialIndex::new(segments.clone());
+        assert_eq!(index.len(), 500);
+
+        for (x, y) in [(3.0, 4.0), (10.5, 10.5), (-5.0, 25.0)] {
+            let p = Point::new(x, y);
+            let (i, d) = index.nearest(&p).unwrap();
+            let expected = segments
+                .iter()
+                .map(|s| p.distance(s))
+                .fold(f64::INFINITY, f64::min);
+            assert_approx_eq!(d, expected, 1e-12);
+            assert_approx_eq!(p.distance(&segments[i]), expected, 1e-12);
+
+            let circle = Circle::new(p.clone(), 2.0);
+            let expected = (0..segments.len())
+                .filter(|&i| p.distance(&segments[i]) <= 2.0)
+                .collect::<Vec<_>>();
+            assert_eq!(index.query_circle(&circle), expected);
+        }
+
+        let area = Aabb::new(Point::new(5.0, 5.0), Point::new(8.0, 7.0));
+        let expected = (0..segments.len())
+            .filter(|&i| segments[i].overlaps(&area))
+            .collect::<Vec<_>>();
+        assert!(!expected.is_empty());
+        assert_eq!(index.query_box(&area), expected);
+
+        // The box of the diagonal meets the corner area, the diagonal does not
+        let diagonal = SpatialIndex::new(vec![Segment::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 4.0),
+        )]);
+        let corner = Aabb::new(Point::new(3.0, 0.0), Point::new(4.0, 1.0));
+        assert!(diagonal.query_box(&corner).is_empty());
+
+        // Every segment crossing the query is a candidate
+        let query = Segment::new(Point::new(0.0, 0.0), Point::new(20.0, 15.0));
+        let candidates = index.candidates(&query);
+        for (i, s) in segments.iter().enumerate() {
+            if s.intersection(&query).is_some() {
+                assert!(candidates.contains(&i));
+            }
+        }
+        assert!(candidates.len() < segments.len() / 4);
+
+        let empty: SpatialIndex<f64, Segment<f64>> = SpatialIndex::new(vec![]);
+        assert!(empty.nearest(&Point::new(0.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_spatial_index_arcs() {
+        let arcs = (0..10)
+            .map(|k| {
+                let circle = DirectedCircle::new(
+                    Circle::new(Point::new(3.0 * k as f64, 0.0), 1.0),
+                    Direction::CounterClockWise,
+                );
+                DirectedArc::new(circle, Radian::new(0.0), Radian::new(std::f64::consts::PI))
+            })
+            .collect::<Vec<_>>();
+        let index = SpatialIndex::new(arcs);
+        // Below the center of the 4th arc, which is the upper half circle: closest to its ends
+        let (i, d) = index.nearest(&Point::new(9.0, -0.5)).unwrap();
+        assert_eq!(i, 3);
+        assert_approx_eq!(d, 1.25_f64.sqrt(), 1e-12);
+        let (i, d) = index.nearest(&Point::new(9.2, 3.0)).unwrap();
+        assert_eq!(i, 3);
+        assert_approx_eq!(d, 0.2_f64.hypot(3.0) - 1.0, 1e-12);
+
+        let query = Segment::new(Point::new(4.0, 0.5), Point::new(7.5, 0.5));
+        assert_eq!(index.candidates(&query), vec![1, 2]);
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+    use sity::*;
+
+    #[test]
+    fn test_min_width() {
+        // Rotated rectangle 10 x 2
+        let (c, s) = (0.6, 0.8);
+        let polygon = Polygon::new(
+            [(0.0, 0.0), (10.0, 0.0), (10.0, 2.0), (0.0, 2.0)]
+                .iter()
+                .map(|(x, y)| Point::new(c * x - s * y, s * x + c * y))
+                .collect(),
+        )
+        .unwrap();
+        let (width, direction) = polygon.min_width().unwrap();
+        assert_approx_eq!(width, 2.0);
+        assert_approx_eq!((direction.dx * s - direction.dy * c).abs(), 0.0);
+
+        let triangle = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        ])
+        .unwrap();
+        let (width, _) = triangle.min_width().unwrap();
+        assert_approx_eq!(width, 2.4);
+    }
+
+    #[test]
+    fn test_sweep_angle_mapping() {
+        // Long thin parallelogram whose edges are not aligned with its length
+        let field = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(30.0, 10.0),
+            Point::new(31.0, 14.0),
+            Point::new(1.0, 4.0),
+        ])
+        .unwrap();
+        let start = Pose::new(Point::new(-2.0, -2.0), Vector::new(1.0, 0.0));
+        let (radius, distance) = (1.0, 1.0);
+
+        let edges = Path::optimal_mapping(&start, radius, distance, &field).unwrap();
+        let width = Path::min_width_mapping(&start, radius, distance, &field).unwrap();
+        assert_approx_eq!(width.length, width.path.length());
+
+        let coarse =
+            Path::sweep_angle_mapping(&start, radius, distance, &field, Radian::new(0.2), 0)
+                .unwrap();
+        let refined =
+            Path::sweep_angle_mapping(&start, radius, distance, &field, Radian::new(0.2), 6)
+                .unwrap();
+        assert_approx_eq!(refined.length, refined.path.length());
+        assert!(refined.length <= coarse.length);
+        assert!(refined.length <= edges.length() + 1e-9);
+        assert!(refined.angle.value() >= 0.0 && refined.angle.value() < std::f64::consts::PI);
+    }
+
+    #[test]
+    fn test_sweep_angle_mapping_invalid_resolution() {
+        let field = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 4.0),
+        ])
+        .unwrap();
+        let start = Pose::new(Point::new(-2.0, -2.0), Vector::new(1.0, 0.0));
+        for resolution in [0.0, -0.1, f64::NAN] {
+            let resolution = Radian::new(resolution);
+            assert!(Path::sweep_angle_mapping(&start, 1.0, 1.0, &field, resolution, 0).is_none());
+        }
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use geomety::geometry2d::*;
+
+    #[test]
+    fn test_tour_order() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let points = vec![
+            Point::new(30.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(40.0, 0.0),
+            Point::new(20.0, 0.0),
+        ];
+        let tour = Path::tour(&start, &points, 1.0, 8).unwrap();
+        assert_eq!(tour.order, vec![1, 3, 0, 2]);
+        // Straight line through all the points
+        assert!((tour.path.length() - 40.0).abs() < 1e-6);
+        assert_eq!(tour.path.trajectories().len(), points.len());
+    }
+
+    #[test]
+    fn test_tour_headings() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(0.0, 1.0));
+        let points = vec![
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+            Point::new(10.0, 0.0),
+            Point::new(5.0, 5.0),
+        ];
+        let radius = 2.0;
+        let tour = Path::tour(&start, &points, radius, 16).unwrap();
+
+        let mut order = tour.order.clone();
+        order.sort();
+        assert_eq!(order, vec![0, 1, 2, 3]);
+        for (traj, &i) in tour.path.trajectories().iter().zip(tour.order.iter()) {
+            assert!(traj.finish().position().distance(&points[i]) < 1e-9);
+        }
+
+        // A continuous path from the start, never shorter than the straight lines between points
+        let trajectories = tour.path.trajectories();
+        assert_eq!(trajectories[0].start(), &start);
+        for w in trajectories.windows(2) {
+            assert_eq!(w[0].finish(), w[1].start());
+        }
+        let mut straight = 0.0;
+        let mut previous = start.position().clone();
+        for &i in tour.order.iter() {
+            straight += previous.distance(&points[i]);
+            previous = points[i].clone();
+        }
+        assert!(tour.path.length() >= straight - 1e-9);
+    }
+}
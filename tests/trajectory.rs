@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+    use sity::*;
+
+    fn assert_point_eq(p1: &Point<f64>, p2: &Point<f64>) {
+        assert_approx_eq!(p1.x, p2.x, 1e-10);
+        assert_approx_eq!(p1.y, p2.y, 1e-10);
+    }
+
+    #[test]
+    fn test_create_all_words() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(1.0, 0.0), Vector::new(-1.0, 0.0));
+        let list = Trajectory::create_all(&start, &finish, 1.0);
+        let csc = list.iter().filter(|t| t.segment().is_some()).count();
+        let ccc = list
+            .iter()
+            .filter(|t| t.middle_rotation().is_some())
+            .count();
+        assert_eq!(csc, 2);
+        assert_eq!(ccc, 4);
+    }
+
+    #[test]
+    fn test_create_ccc_continuity() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(1.0, 0.0), Vector::new(-1.0, 0.0));
+        for traj in Trajectory::create_all(&start, &finish, 1.0) {
+            if let Some(middle) = traj.middle_rotation() {
+                assert_ne!(middle.direction(), traj.start_rotation().direction());
+                assert_point_eq(&traj.start_rotation().start_point(), start.position());
+                assert_point_eq(&traj.start_rotation().finish_point(), &middle.start_point());
+                assert_point_eq(
+                    &middle.finish_point(),
+                    &traj.finish_rotation().start_point(),
+                );
+                assert_point_eq(&traj.finish_rotation().finish_point(), finish.position());
+            }
+        }
+    }
+
+    #[test]
+    fn test_create_ccc_shortest() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(1.0, 0.0), Vector::new(-1.0, 0.0));
+        let traj = Trajectory::create(&start, &finish, 1.0).unwrap();
+        assert!(traj.middle_rotation().is_some());
+        assert_approx_eq!(traj.length(), 7.051978856170865, 1e-10);
+    }
+
+    #[test]
+    fn test_create_csc_far() {
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let finish = Pose::new(Point::new(10.0, 0.0), Vector::new(1.0, 0.0));
+        let traj = Trajectory::create(&start, &finish, 1.0).unwrap();
+        assert!(traj.segment().is_some());
+        assert_approx_eq!(traj.length(), 10.0, 1e-10);
+    }
+
+    #[test]
+    fn test_create_same_circle() {
+        // Both poses on the same left circle: a single arc, whether the centres computed from the
+        // poses are equal or only differ by rounding
+        let pose = |center: &Point<f64>, a: f64| {
+            Pose::new(
+                Point::new(center.x + a.cos(), center.y + a.sin()),
+                Vector::new(-a.sin(), a.cos()),
+            )
+        };
+        let rounded = Point::new(5.3, -4.9);
+        for (start, finish, length) in [
+            (
+                Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0)),
+                Pose::new(Point::new(1.0, 1.0), Vector::new(0.0, 1.0)),
+                std::f64::consts::FRAC_PI_2,
+            ),
+            (pose(&rounded, 2.2), pose(&rounded, 5.1), 2.9),
+        ] {
+            let traj = Trajectory::create(&start, &finish, 1.0).unwrap();
+            assert_approx_eq!(traj.length(), length, 1e-10);
+            assert_approx_eq!(traj.segment().unwrap().length(), 0.0, 1e-10);
+
+            let res = traj.follow(1.0, 100.0);
+            assert_point_eq(res.pose.position(), finish.position());
+            assert_approx_eq!(res.pose.orientation().dx, finish.orientation().dx, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_follow_clockwise_arc() {
+        // Quarter circle from (1, 0) to (0, -1) around the origin, driven clockwise
+        let arc = DirectedArc::new(
+            DirectedCircle::new(Circle::new(Point::new(0.0, 0.0), 1.0), Direction::ClockWise),
+            Radian::new(0.0),
+            Radian::new(std::f64::consts::FRAC_PI_2),
+        );
+        let half = std::f64::consts::FRAC_PI_4;
+        let res = arc.follow(1.0, half);
+        assert_point_eq(res.pose.position(), &Point::new(half.cos(), -half.sin()));
+        assert_approx_eq!(res.pose.orientation().dx, -half.sin(), 1e-10);
+        assert_approx_eq!(res.pose.orientation().dy, -half.cos(), 1e-10);
+    }
+
+    #[test]
+    fn test_create_to_point_cs() {
+        // Quarter turn on the left circle, then straight up to the point
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let point = Point::new(1.0, 5.0);
+        let traj = Trajectory::create_to_point(&start, &point, 1.0).unwrap();
+        assert!(traj.segment().is_some());
+        assert_approx_eq!(traj.length(), std::f64::consts::FRAC_PI_2 + 4.0, 1e-10);
+        assert_point_eq(traj.finish().position(), &point);
+        assert_approx_eq!(traj.finish().orientation().dy, 1.0, 1e-10);
+        assert_approx_eq!(traj.finish_rotation().length(), 0.0, 1e-10);
+
+        let res = traj.follow(1.0, 100.0);
+        assert_approx_eq!(res.complete.unwrap(), traj.length(), 1e-9);
+        assert_point_eq(res.pose.position(), &point);
+    }
+
+    #[test]
+    fn test_create_to_point_cc() {
+        // Inside the left turning circle: a segment is not possible on that side
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let point = Point::new(0.0, 0.5);
+        let all = Trajectory::create_all_to_point(&start, &point, 1.0);
+        assert!(all.iter().any(|t| t.middle_rotation().is_some()));
+        for traj in all.iter() {
+            assert_point_eq(&traj.start_rotation().start_point(), start.position());
+            let next = match traj.middle_rotation() {
+                Some(middle) => {
+                    assert_point_eq(&traj.start_rotation().finish_point(), &middle.start_point());
+                    middle.finish_point()
+                }
+                None => traj.segment().unwrap().second().clone(),
+            };
+            assert_point_eq(&next, &point);
+            assert_point_eq(traj.finish().position(), &point);
+        }
+        let best = Trajectory::create_to_point(&start, &point, 1.0).unwrap();
+        assert!(all.iter().all(|t| best.length() <= t.length()));
+    }
+}
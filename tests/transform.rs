@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+    use sity::*;
+
+    fn assert_point_eq(p1: &Point<f64>, p2: &Point<f64>) {
+        assert_approx_eq!(p1.x, p2.x, 1e-9);
+        assert_approx_eq!(p1.y, p2.y, 1e-9);
+    }
+
+    #[test]
+    fn test_transform_rotation() {
+        let t = Transform2D::<f64>::rotation(Radian::PI / 2.0);
+        let p = t.apply(&Point::new(1.0, 0.0));
+        assert_point_eq(&p, &Point::new(0.0, 1.0));
+
+        let t = Transform2D::new(Radian::PI / 2.0, Vector::new(1.0, 2.0));
+        let p = Point::new(1.0, 1.0).transformed(&t);
+        assert_point_eq(&p, &Point::new(0.0, 3.0));
+    }
+
+    #[test]
+    fn test_transform_compose_inverse() {
+        let a = Transform2D::new(Radian::new(0.3), Vector::new(1.0, -2.0));
+        let b = Transform2D::mirror() * Transform2D::new(Radian::new(1.2), Vector::new(0.5, 4.0));
+        let p = Point::new(3.0, -1.5);
+
+        let composed = &a * &b;
+        assert_point_eq(&composed.apply(&p), &a.apply(&b.apply(&p)));
+
+        for t in [&a, &b, &composed] {
+            let back = t.inverse().apply(&t.apply(&p));
+            assert_point_eq(&back, &p);
+        }
+    }
+
+    #[test]
+    fn test_transform_arc() {
+        let circle = DirectedCircle::new(
+            Circle::new(Point::new(2.0, 1.0), 1.5),
+            Direction::CounterClockWise,
+        );
+        let arc = DirectedArc::new(circle, Radian::new(0.4), Radian::new(1.1));
+        for t in [
+            Transform2D::new(Radian::new(-0.7), Vector::new(3.0, 1.0)),
+            Transform2D::mirror() * Transform2D::translation(Vector::new(-1.0, 2.0)),
+        ] {
+            let moved = arc.transformed(&t);
+            assert_point_eq(&moved.start_point(), &t.apply(&arc.start_point()));
+            assert_point_eq(&moved.finish_point(), &t.apply(&arc.finish_point()));
+            assert_approx_eq!(moved.length(), arc.length(), 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_transform_mirror_direction() {
+        let circle =
+            DirectedCircle::new(Circle::new(Point::new(0.0, 1.0), 1.0), Direction::ClockWise);
+        let mirrored = circle.transformed(&Transform2D::mirror());
+        assert_eq!(mirrored.direction(), Direction::CounterClockWise);
+        assert_point_eq(mirrored.center(), &Point::new(0.0, -1.0));
+    }
+
+    #[test]
+    fn test_transform_from_pose() {
+        let pose = Pose::new(Point::new(1.0, 1.0), Vector::new(0.0, 2.0));
+        let t = Transform2D::from_pose(&pose);
+        assert_point_eq(&t.apply(&Point::new(0.0, 0.0)), &Point::new(1.0, 1.0));
+        assert_point_eq(&t.apply(&Point::new(1.0, 0.0)), &Point::new(1.0, 2.0));
+
+        let local = Pose::new(Point::new(2.0, 0.0), Vector::new(1.0, 0.0));
+        let world = local.transformed(&t);
+        assert_point_eq(world.position(), &Point::new(1.0, 3.0));
+        assert_approx_eq!(world.orientation().dx, 0.0, 1e-9);
+        assert_approx_eq!(world.orientation().dy, 1.0, 1e-9);
+    }
+}
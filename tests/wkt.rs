@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use geomety::geometry2d::*;
+    use sity::*;
+
+    #[test]
+    fn test_wkt_primitives() {
+        let point = Point::new(1.5, -2.0);
+        assert_eq!(point.to_wkt(), "POINT (1.5 -2)");
+        assert_eq!(Point::<f64>::from_wkt("point z(1.5 -2 7)").unwrap(), point);
+
+        let segment = Segment::new(Point::new(0.0, 0.0), Point::new(1.0, 2.5));
+        assert_eq!(segment.to_wkt(), "LINESTRING (0 0, 1 2.5)");
+        assert_eq!(Segment::from_wkt(&segment.to_wkt()).unwrap(), segment);
+
+        let square = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ])
+        .unwrap();
+        assert_eq!(square.to_wkt(), "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))");
+        assert_eq!(Polygon::from_wkt(&square.to_wkt()).unwrap(), square);
+
+        let hole = Polygon::new(vec![
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 1.0),
+            Point::new(2.0, 2.0),
+        ])
+        .unwrap();
+        let field = PolygonWithHoles::new(square.clone(), vec![hole]);
+        assert_eq!(PolygonWithHoles::from_wkt(&field.to_wkt()).unwrap(), field);
+        assert!(Polygon::<f64>::from_wkt(&field.to_wkt()).is_err());
+
+        let fields = MultiPolygon::new(vec![field, square.into()]);
+        assert_eq!(MultiPolygon::from_wkt(&fields.to_wkt()).unwrap(), fields);
+        assert_eq!(
+            MultiPolygon::<f64>::new(vec![]).to_wkt(),
+            "MULTIPOLYGON EMPTY"
+        );
+        assert!(MultiPolygon::<f64>::from_wkt("MULTIPOLYGON EMPTY")
+            .unwrap()
+            .is_empty());
+
+        let points = vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)];
+        assert_eq!(points.to_wkt(), "MULTIPOINT ((0 0), (1 1))");
+        assert_eq!(
+            Vec::<Point<f64>>::from_wkt("MULTIPOINT (0 0, 1 1)").unwrap(),
+            points
+        );
+        let segments = vec![segment.clone(), segment];
+        assert_eq!(
+            Vec::<Segment<f64>>::from_wkt(&segments.to_wkt()).unwrap(),
+            segments
+        );
+    }
+
+    #[test]
+    fn test_wkt_errors() {
+        let error = Point::<f64>::from_wkt("POINT (1 x)").unwrap_err();
+        assert_eq!(error.position, 9);
+        assert_eq!(error.to_string(), "expected a number at position 9");
+
+        let error = Point::<f64>::from_wkt("  PIONT (1 2)").unwrap_err();
+        assert_eq!(error, WktError::new(2, "expected `POINT`"));
+
+        let error = Segment::<f64>::from_wkt("LINESTRING (0 0, 1 1, 2 2)").unwrap_err();
+        assert_eq!(error.position, 11);
+
+        let error = Point::<f64>::from_wkt("POINT (1 2) POINT").unwrap_err();
+        assert_eq!(error.position, 12);
+
+        let error = Polygon::<f64>::from_wkt("POLYGON ((0 0, 1 0, 2 0, 0 0))").unwrap_err();
+        assert_eq!(error.position, 9);
+        assert!(error.message.contains("edges 0 and 2 intersect"));
+
+        let error = Polygon::<f64>::from_wkt("POLYGON ((0 0, 1 0, 1 1))").unwrap_err();
+        assert_eq!(error.message, "expected a closed ring");
+
+        let error = DirectedArc::<f64>::from_wkt("CIRCULARSTRING (0 0, 1 1, 2 2)").unwrap_err();
+        assert_eq!(error.position, 15);
+    }
+
+    #[test]
+    fn test_wkt_arcs() {
+        let circle =
+            DirectedCircle::new(Circle::new(Point::new(1.0, 2.0), 2.0), Direction::ClockWise);
+        let arc = DirectedArc::new(circle, Radian::new(0.5), Radian::new(4.0));
+        let read = DirectedArc::<f64>::from_wkt(&arc.to_wkt()).unwrap();
+        assert_eq!(read.direction(), Direction::ClockWise);
+        assert_approx_eq!(read.center().x, 1.0);
+        assert_approx_eq!(read.center().y, 2.0);
+        assert_approx_eq!(read.radius(), 2.0);
+        assert_approx_eq!(read.start_angle().value(), 0.5);
+        assert_approx_eq!(read.delta_angle().value(), 4.0);
+
+        let full = DirectedArc::<f64>::from_wkt("CIRCULARSTRING (1 0, -1 0, 1 0)").unwrap();
+        assert_approx_eq!(full.radius(), 1.0);
+        assert_approx_eq!(full.delta_angle().value(), 2.0 * std::f64::consts::PI);
+
+        // A CSC path, with a rotation of zero length, and a CCC path
+        let start = Pose::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+        let poses = vec![
+            start.clone(),
+            Pose::new(Point::new(5.0, 0.0), Vector::new(1.0, 0.0)),
+            Pose::new(Point::new(5.0, 3.0), Vector::new(-1.0, 0.0)),
+            Pose::new(Point::new(5.0, 2.0), Vector::new(1.0, 0.0)),
+        ];
+        let path = Path::create(1.0, &poses).unwrap();
+        let wkt = path.to_wkt();
+        assert!(wkt.starts_with("MULTICURVE (COMPOUNDCURVE (CIRCULARSTRING ("));
+        assert!(wkt.contains("(0 0, 5 0), CIRCULARSTRING (5 0, 5 0, 5 0))"));
+        let read = Path::<f64>::from_wkt(&wkt).unwrap();
+        assert_eq!(read.trajectories().len(), 3);
+        assert_approx_eq!(read.length(), path.length());
+        for (a, b) in read.trajectories().iter().zip(path.trajectories()) {
+            assert_eq!(a.middle_rotation().is_some(), b.middle_rotation().is_some());
+            for (p, q) in [(a.start(), b.start()), (a.finish(), b.finish())] {
+                assert_approx_eq!(p.position().x, q.position().x);
+                assert_approx_eq!(p.position().y, q.position().y);
+                assert_approx_eq!(p.orientation().dx, q.orientation().dx);
+                assert_approx_eq!(p.orientation().dy, q.orientation().dy);
+            }
+        }
+        assert_eq!(
+            Path::<f64>::from_wkt("MULTICURVE EMPTY")
+                .unwrap()
+                .trajectories()
+                .len(),
+            0
+        );
+    }
+}